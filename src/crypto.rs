@@ -13,7 +13,7 @@ pub fn generate_keypair() -> Result<KeypairResponse> {
     })
 }
 
-pub fn sign_message(message: &str, secret_key_b58: &str) -> Result<SignMessageResponse> {
+pub fn keypair_from_secret(secret_key_b58: &str) -> Result<Keypair> {
     let secret_bytes = bs58::decode(secret_key_b58)
         .into_vec()
         .map_err(|_| anyhow!("Invalid base58 secret key"))?;
@@ -22,8 +22,12 @@ pub fn sign_message(message: &str, secret_key_b58: &str) -> Result<SignMessageRe
         return Err(anyhow!("Secret key must be 64 bytes"));
     }
     
-    let keypair = Keypair::from_bytes(&secret_bytes)
-        .map_err(|_| anyhow!("Invalid secret key format"))?;
+    Keypair::from_bytes(&secret_bytes)
+        .map_err(|_| anyhow!("Invalid secret key format"))
+}
+
+pub fn sign_message(message: &str, secret_key_b58: &str) -> Result<SignMessageResponse> {
+    let keypair = keypair_from_secret(secret_key_b58)?;
     
     let message_bytes = message.as_bytes();
     let signature = keypair.sign_message(message_bytes);
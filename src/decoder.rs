@@ -0,0 +1,1383 @@
+use solana_sdk::{
+    pubkey::Pubkey,
+    system_instruction::SystemInstruction,
+    system_program,
+};
+use spl_token::instruction::TokenInstruction;
+use spl_token_2022::{
+    extension::{
+        default_account_state::instruction::DefaultAccountStateInstruction,
+        interest_bearing_mint::instruction::{
+            InitializeInstructionData as InterestBearingInitializeData,
+            InterestBearingMintInstruction,
+        },
+        interest_bearing_mint::BasisPoints,
+        metadata_pointer::instruction::{
+            InitializeInstructionData as MetadataPointerInitializeData,
+            MetadataPointerInstruction,
+            UpdateInstructionData as MetadataPointerUpdateData,
+        },
+        transfer_fee::instruction::TransferFeeInstruction,
+    },
+    instruction::{
+        decode_instruction_data,
+        decode_instruction_type,
+        TokenInstruction as Token2022Instruction,
+    },
+    state::AccountState,
+};
+use spl_token_metadata_interface::{instruction::TokenMetadataInstruction, state::Field};
+use anyhow::{Result, anyhow};
+use base64::{Engine as _, engine::general_purpose};
+use serde_json::{json, Value};
+use crate::types::{DecodedInstruction, InstructionResponse};
+use crate::solana_ops;
+
+struct Parsed {
+    instruction_type: &'static str,
+    info: Value,
+    description: String,
+}
+
+pub fn decode_instruction(program_id: &Pubkey, accounts: &[String], data: &[u8]) -> DecodedInstruction {
+    let (program, parsed) = if *program_id == system_program::ID {
+        ("system", decode_system_instruction(accounts, data))
+    } else if *program_id == spl_token::ID {
+        ("spl-token", decode_token_instruction(accounts, data))
+    } else if *program_id == spl_token_2022::ID {
+        ("spl-token-2022", decode_token_2022_instruction(accounts, data))
+    } else if *program_id == spl_associated_token_account::ID {
+        ("spl-associated-token-account", decode_ata_instruction(accounts, data))
+    } else if *program_id == spl_memo::ID || *program_id == spl_memo::v1::ID {
+        ("spl-memo", decode_memo_instruction(accounts, data))
+    } else {
+        ("unknown", Err(anyhow!("Unsupported program")))
+    };
+    
+    match parsed {
+        Ok(parsed) => DecodedInstruction {
+            program: program.to_string(),
+            program_id: program_id.to_string(),
+            instruction_type: parsed.instruction_type.to_string(),
+            info: parsed.info,
+            description: parsed.description,
+            error: None,
+        },
+        Err(e) => DecodedInstruction {
+            program: program.to_string(),
+            program_id: program_id.to_string(),
+            instruction_type: "Unknown".to_string(),
+            info: json!({
+                "accounts": accounts,
+                "data": general_purpose::STANDARD.encode(data),
+            }),
+            description: format!("Unrecognized instruction for program {}", program_id),
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+pub fn decode_instruction_response(spec: &InstructionResponse) -> Result<DecodedInstruction> {
+    let instruction = solana_ops::instruction_from_response(spec)?;
+    let accounts: Vec<String> = instruction.accounts
+        .iter()
+        .map(|meta| meta.pubkey.to_string())
+        .collect();
+    
+    Ok(decode_instruction(&instruction.program_id, &accounts, &instruction.data))
+}
+
+fn account(accounts: &[String], index: usize) -> Result<String> {
+    accounts.get(index)
+        .cloned()
+        .ok_or_else(|| anyhow!("Missing account at index {}", index))
+}
+
+fn extra_signers(accounts: &[String], start: usize) -> Vec<String> {
+    accounts.get(start..).map(|signers| signers.to_vec()).unwrap_or_default()
+}
+
+fn decode_system_instruction(accounts: &[String], data: &[u8]) -> Result<Parsed> {
+    let instruction: SystemInstruction = bincode::deserialize(data)
+        .map_err(|_| anyhow!("Invalid system instruction data"))?;
+    
+    let parsed = match instruction {
+        SystemInstruction::CreateAccount { lamports, space, owner } => {
+            let from = account(accounts, 0)?;
+            let new_account = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "CreateAccount",
+                description: format!(
+                    "Create account {} owned by {} with {} bytes, funded with {} lamports from {}",
+                    new_account, owner, space, lamports, from,
+                ),
+                info: json!({
+                    "from": from,
+                    "new_account": new_account,
+                    "lamports": lamports,
+                    "space": space,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::Assign { owner } => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "Assign",
+                description: format!("Assign account {} to program {}", target, owner),
+                info: json!({
+                    "account": target,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::Transfer { lamports } => {
+            let from = account(accounts, 0)?;
+            let to = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "Transfer",
+                description: format!("Transfer {} lamports from {} to {}", lamports, from, to),
+                info: json!({
+                    "from": from,
+                    "to": to,
+                    "lamports": lamports,
+                }),
+            }
+        }
+        SystemInstruction::CreateAccountWithSeed { base, seed, lamports, space, owner } => {
+            let from = account(accounts, 0)?;
+            let new_account = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "CreateAccountWithSeed",
+                description: format!(
+                    "Create account {} from base {} and seed \"{}\" owned by {} with {} bytes, funded with {} lamports from {}",
+                    new_account, base, seed, owner, space, lamports, from,
+                ),
+                info: json!({
+                    "from": from,
+                    "new_account": new_account,
+                    "base": base.to_string(),
+                    "seed": seed,
+                    "lamports": lamports,
+                    "space": space,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::AdvanceNonceAccount => {
+            let nonce_account = account(accounts, 0)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "AdvanceNonceAccount",
+                description: format!("Advance nonce account {} with authority {}", nonce_account, authority),
+                info: json!({
+                    "nonce_account": nonce_account,
+                    "nonce_authority": authority,
+                }),
+            }
+        }
+        SystemInstruction::WithdrawNonceAccount(lamports) => {
+            let nonce_account = account(accounts, 0)?;
+            let to = account(accounts, 1)?;
+            let authority = account(accounts, 4)?;
+            Parsed {
+                instruction_type: "WithdrawNonceAccount",
+                description: format!("Withdraw {} lamports from nonce account {} to {}", lamports, nonce_account, to),
+                info: json!({
+                    "nonce_account": nonce_account,
+                    "to": to,
+                    "nonce_authority": authority,
+                    "lamports": lamports,
+                }),
+            }
+        }
+        SystemInstruction::InitializeNonceAccount(authority) => {
+            let nonce_account = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "InitializeNonceAccount",
+                description: format!("Initialize nonce account {} with authority {}", nonce_account, authority),
+                info: json!({
+                    "nonce_account": nonce_account,
+                    "nonce_authority": authority.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::AuthorizeNonceAccount(new_authority) => {
+            let nonce_account = account(accounts, 0)?;
+            let authority = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "AuthorizeNonceAccount",
+                description: format!("Set authority of nonce account {} to {}", nonce_account, new_authority),
+                info: json!({
+                    "nonce_account": nonce_account,
+                    "nonce_authority": authority,
+                    "new_authority": new_authority.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::Allocate { space } => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "Allocate",
+                description: format!("Allocate {} bytes for account {}", space, target),
+                info: json!({
+                    "account": target,
+                    "space": space,
+                }),
+            }
+        }
+        SystemInstruction::AllocateWithSeed { base, seed, space, owner } => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "AllocateWithSeed",
+                description: format!("Allocate {} bytes for account {} owned by {}", space, target, owner),
+                info: json!({
+                    "account": target,
+                    "base": base.to_string(),
+                    "seed": seed,
+                    "space": space,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::AssignWithSeed { base, seed, owner } => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "AssignWithSeed",
+                description: format!("Assign account {} to program {}", target, owner),
+                info: json!({
+                    "account": target,
+                    "base": base.to_string(),
+                    "seed": seed,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        SystemInstruction::TransferWithSeed { lamports, from_seed, from_owner } => {
+            let from = account(accounts, 0)?;
+            let base = account(accounts, 1)?;
+            let to = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "TransferWithSeed",
+                description: format!("Transfer {} lamports from {} to {}", lamports, from, to),
+                info: json!({
+                    "from": from,
+                    "base": base,
+                    "to": to,
+                    "from_seed": from_seed,
+                    "from_owner": from_owner.to_string(),
+                    "lamports": lamports,
+                }),
+            }
+        }
+        SystemInstruction::UpgradeNonceAccount => {
+            let nonce_account = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "UpgradeNonceAccount",
+                description: format!("Upgrade nonce account {}", nonce_account),
+                info: json!({
+                    "nonce_account": nonce_account,
+                }),
+            }
+        }
+    };
+    
+    Ok(parsed)
+}
+
+fn decode_token_instruction(accounts: &[String], data: &[u8]) -> Result<Parsed> {
+    let instruction = TokenInstruction::unpack(data)
+        .map_err(|_| anyhow!("Invalid SPL Token instruction data"))?;
+    
+    let parsed = match instruction {
+        TokenInstruction::InitializeMint { decimals, mint_authority, freeze_authority } => {
+            let mint = account(accounts, 0)?;
+            let freeze_authority: Option<Pubkey> = freeze_authority.into();
+            Parsed {
+                instruction_type: "InitializeMint",
+                description: format!("Initialize mint {} with {} decimals and mint authority {}", mint, decimals, mint_authority),
+                info: json!({
+                    "mint": mint,
+                    "decimals": decimals,
+                    "mint_authority": mint_authority.to_string(),
+                    "freeze_authority": freeze_authority.map(|key| key.to_string()),
+                }),
+            }
+        }
+        TokenInstruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => {
+            let mint = account(accounts, 0)?;
+            let freeze_authority: Option<Pubkey> = freeze_authority.into();
+            Parsed {
+                instruction_type: "InitializeMint2",
+                description: format!("Initialize mint {} with {} decimals and mint authority {}", mint, decimals, mint_authority),
+                info: json!({
+                    "mint": mint,
+                    "decimals": decimals,
+                    "mint_authority": mint_authority.to_string(),
+                    "freeze_authority": freeze_authority.map(|key| key.to_string()),
+                }),
+            }
+        }
+        TokenInstruction::InitializeAccount => {
+            let token_account = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "InitializeAccount",
+                description: format!("Initialize token account {} for mint {} owned by {}", token_account, mint, owner),
+                info: json!({
+                    "account": token_account,
+                    "mint": mint,
+                    "owner": owner,
+                }),
+            }
+        }
+        TokenInstruction::InitializeAccount2 { owner } => {
+            let token_account = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "InitializeAccount2",
+                description: format!("Initialize token account {} for mint {} owned by {}", token_account, mint, owner),
+                info: json!({
+                    "account": token_account,
+                    "mint": mint,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        TokenInstruction::InitializeAccount3 { owner } => {
+            let token_account = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "InitializeAccount3",
+                description: format!("Initialize token account {} for mint {} owned by {}", token_account, mint, owner),
+                info: json!({
+                    "account": token_account,
+                    "mint": mint,
+                    "owner": owner.to_string(),
+                }),
+            }
+        }
+        TokenInstruction::InitializeMultisig { m } => {
+            let multisig = account(accounts, 0)?;
+            let signers = extra_signers(accounts, 2);
+            Parsed {
+                instruction_type: "InitializeMultisig",
+                description: format!("Initialize {}-of-{} multisig {}", m, signers.len(), multisig),
+                info: json!({
+                    "multisig": multisig,
+                    "m": m,
+                    "signers": signers,
+                }),
+            }
+        }
+        TokenInstruction::InitializeMultisig2 { m } => {
+            let multisig = account(accounts, 0)?;
+            let signers = extra_signers(accounts, 1);
+            Parsed {
+                instruction_type: "InitializeMultisig2",
+                description: format!("Initialize {}-of-{} multisig {}", m, signers.len(), multisig),
+                info: json!({
+                    "multisig": multisig,
+                    "m": m,
+                    "signers": signers,
+                }),
+            }
+        }
+        TokenInstruction::Transfer { amount } => {
+            let source = account(accounts, 0)?;
+            let destination = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "Transfer",
+                description: format!("Transfer {} base units from {} to {}", amount, source, destination),
+                info: json!({
+                    "source": source,
+                    "destination": destination,
+                    "owner": owner,
+                    "amount": amount,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::TransferChecked { amount, decimals } => {
+            let source = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let destination = account(accounts, 2)?;
+            let owner = account(accounts, 3)?;
+            Parsed {
+                instruction_type: "TransferChecked",
+                description: format!("Transfer {} base units of {} from {} to {}", amount, mint, source, destination),
+                info: json!({
+                    "source": source,
+                    "mint": mint,
+                    "destination": destination,
+                    "owner": owner,
+                    "amount": amount,
+                    "decimals": decimals,
+                    "multisig_signers": extra_signers(accounts, 4),
+                }),
+            }
+        }
+        TokenInstruction::Approve { amount } => {
+            let source = account(accounts, 0)?;
+            let delegate = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "Approve",
+                description: format!("Approve {} to spend {} base units from {}", delegate, amount, source),
+                info: json!({
+                    "source": source,
+                    "delegate": delegate,
+                    "owner": owner,
+                    "amount": amount,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::ApproveChecked { amount, decimals } => {
+            let source = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let delegate = account(accounts, 2)?;
+            let owner = account(accounts, 3)?;
+            Parsed {
+                instruction_type: "ApproveChecked",
+                description: format!("Approve {} to spend {} base units of {} from {}", delegate, amount, mint, source),
+                info: json!({
+                    "source": source,
+                    "mint": mint,
+                    "delegate": delegate,
+                    "owner": owner,
+                    "amount": amount,
+                    "decimals": decimals,
+                    "multisig_signers": extra_signers(accounts, 4),
+                }),
+            }
+        }
+        TokenInstruction::Revoke => {
+            let source = account(accounts, 0)?;
+            let owner = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "Revoke",
+                description: format!("Revoke delegate of {}", source),
+                info: json!({
+                    "source": source,
+                    "owner": owner,
+                    "multisig_signers": extra_signers(accounts, 2),
+                }),
+            }
+        }
+        TokenInstruction::SetAuthority { authority_type, new_authority } => {
+            let target = account(accounts, 0)?;
+            let authority = account(accounts, 1)?;
+            let new_authority: Option<Pubkey> = new_authority.into();
+            Parsed {
+                instruction_type: "SetAuthority",
+                description: match new_authority {
+                    Some(key) => format!("Set {:?} authority of {} to {}", authority_type, target, key),
+                    None => format!("Revoke {:?} authority of {}", authority_type, target),
+                },
+                info: json!({
+                    "account": target,
+                    "authority": authority,
+                    "authority_type": format!("{:?}", authority_type),
+                    "new_authority": new_authority.map(|key| key.to_string()),
+                    "multisig_signers": extra_signers(accounts, 2),
+                }),
+            }
+        }
+        TokenInstruction::MintTo { amount } => {
+            let mint = account(accounts, 0)?;
+            let destination = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "MintTo",
+                description: format!("Mint {} base units of {} to {}", amount, mint, destination),
+                info: json!({
+                    "mint": mint,
+                    "destination": destination,
+                    "mint_authority": authority,
+                    "amount": amount,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::MintToChecked { amount, decimals } => {
+            let mint = account(accounts, 0)?;
+            let destination = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "MintToChecked",
+                description: format!("Mint {} base units of {} to {}", amount, mint, destination),
+                info: json!({
+                    "mint": mint,
+                    "destination": destination,
+                    "mint_authority": authority,
+                    "amount": amount,
+                    "decimals": decimals,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::Burn { amount } => {
+            let source = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "Burn",
+                description: format!("Burn {} base units of {} from {}", amount, mint, source),
+                info: json!({
+                    "account": source,
+                    "mint": mint,
+                    "owner": owner,
+                    "amount": amount,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::BurnChecked { amount, decimals } => {
+            let source = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "BurnChecked",
+                description: format!("Burn {} base units of {} from {}", amount, mint, source),
+                info: json!({
+                    "account": source,
+                    "mint": mint,
+                    "owner": owner,
+                    "amount": amount,
+                    "decimals": decimals,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::CloseAccount => {
+            let target = account(accounts, 0)?;
+            let destination = account(accounts, 1)?;
+            let owner = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "CloseAccount",
+                description: format!("Close token account {} and send its lamports to {}", target, destination),
+                info: json!({
+                    "account": target,
+                    "destination": destination,
+                    "owner": owner,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::FreezeAccount => {
+            let target = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "FreezeAccount",
+                description: format!("Freeze token account {} of mint {}", target, mint),
+                info: json!({
+                    "account": target,
+                    "mint": mint,
+                    "freeze_authority": authority,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::ThawAccount => {
+            let target = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "ThawAccount",
+                description: format!("Thaw token account {} of mint {}", target, mint),
+                info: json!({
+                    "account": target,
+                    "mint": mint,
+                    "freeze_authority": authority,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TokenInstruction::SyncNative => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "SyncNative",
+                description: format!("Sync native SOL balance of {}", target),
+                info: json!({
+                    "account": target,
+                }),
+            }
+        }
+        TokenInstruction::GetAccountDataSize => {
+            let mint = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "GetAccountDataSize",
+                description: format!("Get token account size for mint {}", mint),
+                info: json!({
+                    "mint": mint,
+                }),
+            }
+        }
+        TokenInstruction::InitializeImmutableOwner => {
+            let target = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "InitializeImmutableOwner",
+                description: format!("Make the owner of token account {} immutable", target),
+                info: json!({
+                    "account": target,
+                }),
+            }
+        }
+        TokenInstruction::AmountToUiAmount { amount } => {
+            let mint = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "AmountToUiAmount",
+                description: format!("Convert {} base units of {} to a UI amount", amount, mint),
+                info: json!({
+                    "mint": mint,
+                    "amount": amount,
+                }),
+            }
+        }
+        TokenInstruction::UiAmountToAmount { ui_amount } => {
+            let mint = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "UiAmountToAmount",
+                description: format!("Convert UI amount {} of {} to base units", ui_amount, mint),
+                info: json!({
+                    "mint": mint,
+                    "ui_amount": ui_amount,
+                }),
+            }
+        }
+    };
+    
+    Ok(parsed)
+}
+
+// Token-2022 shares the SPL Token layout for the base instructions, so only the
+// extension instructions and the token-metadata interface are decoded here.
+fn decode_token_2022_instruction(accounts: &[String], data: &[u8]) -> Result<Parsed> {
+    let instruction = match Token2022Instruction::unpack(data) {
+        Ok(instruction) => instruction,
+        Err(_) => {
+            let instruction = TokenMetadataInstruction::unpack(data)
+                .map_err(|_| anyhow!("Invalid SPL Token-2022 instruction data"))?;
+            return decode_token_metadata_instruction(accounts, instruction);
+        }
+    };
+    let extension_data = data.get(1..).unwrap_or_default();
+    
+    let parsed = match instruction {
+        // Token-2022 adds authority types that SPL Token cannot unpack.
+        Token2022Instruction::SetAuthority { authority_type, new_authority } => {
+            let target = account(accounts, 0)?;
+            let authority = account(accounts, 1)?;
+            let new_authority: Option<Pubkey> = new_authority.into();
+            Parsed {
+                instruction_type: "SetAuthority",
+                description: match new_authority {
+                    Some(key) => format!("Set {:?} authority of {} to {}", authority_type, target, key),
+                    None => format!("Revoke {:?} authority of {}", authority_type, target),
+                },
+                info: json!({
+                    "account": target,
+                    "authority": authority,
+                    "authority_type": format!("{:?}", authority_type),
+                    "new_authority": new_authority.map(|key| key.to_string()),
+                    "multisig_signers": extra_signers(accounts, 2),
+                }),
+            }
+        }
+        Token2022Instruction::InitializeMintCloseAuthority { close_authority } => {
+            let mint = account(accounts, 0)?;
+            let close_authority: Option<Pubkey> = close_authority.into();
+            Parsed {
+                instruction_type: "InitializeMintCloseAuthority",
+                description: match close_authority {
+                    Some(key) => format!("Set close authority of mint {} to {}", mint, key),
+                    None => format!("Initialize mint {} without a close authority", mint),
+                },
+                info: json!({
+                    "mint": mint,
+                    "close_authority": close_authority.map(|key| key.to_string()),
+                }),
+            }
+        }
+        Token2022Instruction::TransferFeeExtension(instruction) => {
+            decode_transfer_fee_instruction(accounts, instruction)?
+        }
+        Token2022Instruction::DefaultAccountStateExtension => {
+            let mint = account(accounts, 0)?;
+            let instruction_type = decode_instruction_type::<DefaultAccountStateInstruction>(extension_data)
+                .map_err(|_| anyhow!("Invalid default account state instruction data"))?;
+            let state = decode_instruction_data::<u8>(extension_data)
+                .ok()
+                .and_then(|state| AccountState::try_from(*state).ok())
+                .ok_or_else(|| anyhow!("Invalid default account state"))?;
+            let state = format!("{:?}", state);
+            match instruction_type {
+                DefaultAccountStateInstruction::Initialize => Parsed {
+                    instruction_type: "InitializeDefaultAccountState",
+                    description: format!("Initialize default account state of mint {} to {}", mint, state),
+                    info: json!({
+                        "mint": mint,
+                        "account_state": state,
+                    }),
+                },
+                DefaultAccountStateInstruction::Update => {
+                    let authority = account(accounts, 1)?;
+                    Parsed {
+                        instruction_type: "UpdateDefaultAccountState",
+                        description: format!("Update default account state of mint {} to {}", mint, state),
+                        info: json!({
+                            "mint": mint,
+                            "freeze_authority": authority,
+                            "account_state": state,
+                            "multisig_signers": extra_signers(accounts, 2),
+                        }),
+                    }
+                }
+            }
+        }
+        Token2022Instruction::InterestBearingMintExtension => {
+            let mint = account(accounts, 0)?;
+            let instruction_type = decode_instruction_type::<InterestBearingMintInstruction>(extension_data)
+                .map_err(|_| anyhow!("Invalid interest-bearing mint instruction data"))?;
+            match instruction_type {
+                InterestBearingMintInstruction::Initialize => {
+                    let instruction_data = decode_instruction_data::<InterestBearingInitializeData>(extension_data)
+                        .map_err(|_| anyhow!("Invalid interest-bearing mint instruction data"))?;
+                    let rate_authority: Option<Pubkey> = instruction_data.rate_authority.into();
+                    let rate = i16::from(instruction_data.rate);
+                    Parsed {
+                        instruction_type: "InitializeInterestBearingConfig",
+                        description: format!("Initialize interest-bearing mint {} at {} basis points", mint, rate),
+                        info: json!({
+                            "mint": mint,
+                            "rate_authority": rate_authority.map(|key| key.to_string()),
+                            "rate": rate,
+                        }),
+                    }
+                }
+                InterestBearingMintInstruction::UpdateRate => {
+                    let rate = decode_instruction_data::<BasisPoints>(extension_data)
+                        .map_err(|_| anyhow!("Invalid interest-bearing mint instruction data"))?;
+                    let rate = i16::from(*rate);
+                    let authority = account(accounts, 1)?;
+                    Parsed {
+                        instruction_type: "UpdateInterestBearingConfigRate",
+                        description: format!("Update interest rate of mint {} to {} basis points", mint, rate),
+                        info: json!({
+                            "mint": mint,
+                            "rate_authority": authority,
+                            "rate": rate,
+                            "multisig_signers": extra_signers(accounts, 2),
+                        }),
+                    }
+                }
+            }
+        }
+        Token2022Instruction::MetadataPointerExtension => {
+            let mint = account(accounts, 0)?;
+            let instruction_type = decode_instruction_type::<MetadataPointerInstruction>(extension_data)
+                .map_err(|_| anyhow!("Invalid metadata pointer instruction data"))?;
+            match instruction_type {
+                MetadataPointerInstruction::Initialize => {
+                    let instruction_data = decode_instruction_data::<MetadataPointerInitializeData>(extension_data)
+                        .map_err(|_| anyhow!("Invalid metadata pointer instruction data"))?;
+                    let authority: Option<Pubkey> = instruction_data.authority.into();
+                    let metadata_address: Option<Pubkey> = instruction_data.metadata_address.into();
+                    Parsed {
+                        instruction_type: "InitializeMetadataPointer",
+                        description: match metadata_address {
+                            Some(key) => format!("Point metadata of mint {} to {}", mint, key),
+                            None => format!("Initialize metadata pointer of mint {}", mint),
+                        },
+                        info: json!({
+                            "mint": mint,
+                            "authority": authority.map(|key| key.to_string()),
+                            "metadata_address": metadata_address.map(|key| key.to_string()),
+                        }),
+                    }
+                }
+                MetadataPointerInstruction::Update => {
+                    let instruction_data = decode_instruction_data::<MetadataPointerUpdateData>(extension_data)
+                        .map_err(|_| anyhow!("Invalid metadata pointer instruction data"))?;
+                    let metadata_address: Option<Pubkey> = instruction_data.metadata_address.into();
+                    let authority = account(accounts, 1)?;
+                    Parsed {
+                        instruction_type: "UpdateMetadataPointer",
+                        description: match metadata_address {
+                            Some(key) => format!("Point metadata of mint {} to {}", mint, key),
+                            None => format!("Clear metadata pointer of mint {}", mint),
+                        },
+                        info: json!({
+                            "mint": mint,
+                            "authority": authority,
+                            "metadata_address": metadata_address.map(|key| key.to_string()),
+                            "multisig_signers": extra_signers(accounts, 2),
+                        }),
+                    }
+                }
+            }
+        }
+        Token2022Instruction::InitializeNonTransferableMint => {
+            let mint = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "InitializeNonTransferableMint",
+                description: format!("Make mint {} non-transferable", mint),
+                info: json!({
+                    "mint": mint,
+                }),
+            }
+        }
+        Token2022Instruction::InitializePermanentDelegate { delegate } => {
+            let mint = account(accounts, 0)?;
+            Parsed {
+                instruction_type: "InitializePermanentDelegate",
+                description: format!("Set permanent delegate of mint {} to {}", mint, delegate),
+                info: json!({
+                    "mint": mint,
+                    "delegate": delegate.to_string(),
+                }),
+            }
+        }
+        Token2022Instruction::CreateNativeMint => {
+            let payer = account(accounts, 0)?;
+            let native_mint = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "CreateNativeMint",
+                description: format!("Create native mint {}, paid by {}", native_mint, payer),
+                info: json!({
+                    "payer": payer,
+                    "native_mint": native_mint,
+                }),
+            }
+        }
+        Token2022Instruction::WithdrawExcessLamports => {
+            let source = account(accounts, 0)?;
+            let destination = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "WithdrawExcessLamports",
+                description: format!("Withdraw excess lamports from {} to {}", source, destination),
+                info: json!({
+                    "source": source,
+                    "destination": destination,
+                    "authority": authority,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        Token2022Instruction::Reallocate { .. }
+        | Token2022Instruction::ConfidentialTransferExtension
+        | Token2022Instruction::MemoTransferExtension
+        | Token2022Instruction::CpiGuardExtension
+        | Token2022Instruction::TransferHookExtension
+        | Token2022Instruction::ConfidentialTransferFeeExtension
+        | Token2022Instruction::GroupPointerExtension
+        | Token2022Instruction::GroupMemberPointerExtension => {
+            return Err(anyhow!("Unsupported SPL Token-2022 extension instruction"));
+        }
+        _ => return decode_token_instruction(accounts, data),
+    };
+    
+    Ok(parsed)
+}
+
+fn decode_transfer_fee_instruction(accounts: &[String], instruction: TransferFeeInstruction) -> Result<Parsed> {
+    let mint = account(accounts, 0)?;
+    
+    let parsed = match instruction {
+        TransferFeeInstruction::InitializeTransferFeeConfig {
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        } => {
+            let transfer_fee_config_authority: Option<Pubkey> = transfer_fee_config_authority.into();
+            let withdraw_withheld_authority: Option<Pubkey> = withdraw_withheld_authority.into();
+            Parsed {
+                instruction_type: "InitializeTransferFeeConfig",
+                description: format!("Initialize transfer fee of {} basis points (max {}) on mint {}", transfer_fee_basis_points, maximum_fee, mint),
+                info: json!({
+                    "mint": mint,
+                    "transfer_fee_config_authority": transfer_fee_config_authority.map(|key| key.to_string()),
+                    "withdraw_withheld_authority": withdraw_withheld_authority.map(|key| key.to_string()),
+                    "transfer_fee_basis_points": transfer_fee_basis_points,
+                    "maximum_fee": maximum_fee,
+                }),
+            }
+        }
+        TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee } => {
+            let source = account(accounts, 0)?;
+            let mint = account(accounts, 1)?;
+            let destination = account(accounts, 2)?;
+            let owner = account(accounts, 3)?;
+            Parsed {
+                instruction_type: "TransferCheckedWithFee",
+                description: format!("Transfer {} base units of {} from {} to {} with fee {}", amount, mint, source, destination, fee),
+                info: json!({
+                    "source": source,
+                    "mint": mint,
+                    "destination": destination,
+                    "owner": owner,
+                    "amount": amount,
+                    "decimals": decimals,
+                    "fee": fee,
+                    "multisig_signers": extra_signers(accounts, 4),
+                }),
+            }
+        }
+        TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+            let destination = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            Parsed {
+                instruction_type: "WithdrawWithheldTokensFromMint",
+                description: format!("Withdraw withheld fees of mint {} to {}", mint, destination),
+                info: json!({
+                    "mint": mint,
+                    "destination": destination,
+                    "withdraw_withheld_authority": authority,
+                    "multisig_signers": extra_signers(accounts, 3),
+                }),
+            }
+        }
+        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+            let destination = account(accounts, 1)?;
+            let authority = account(accounts, 2)?;
+            let remaining = extra_signers(accounts, 3);
+            let split = remaining.len()
+                .checked_sub(num_token_accounts as usize)
+                .ok_or_else(|| anyhow!("Missing source token accounts"))?;
+            let (signers, sources) = remaining.split_at(split);
+            Parsed {
+                instruction_type: "WithdrawWithheldTokensFromAccounts",
+                description: format!("Withdraw withheld fees of mint {} from {} accounts to {}", mint, num_token_accounts, destination),
+                info: json!({
+                    "mint": mint,
+                    "destination": destination,
+                    "withdraw_withheld_authority": authority,
+                    "sources": sources,
+                    "multisig_signers": signers,
+                }),
+            }
+        }
+        TransferFeeInstruction::HarvestWithheldTokensToMint => {
+            let sources = extra_signers(accounts, 1);
+            Parsed {
+                instruction_type: "HarvestWithheldTokensToMint",
+                description: format!("Harvest withheld fees from {} accounts into mint {}", sources.len(), mint),
+                info: json!({
+                    "mint": mint,
+                    "sources": sources,
+                }),
+            }
+        }
+        TransferFeeInstruction::SetTransferFee { transfer_fee_basis_points, maximum_fee } => {
+            let authority = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "SetTransferFee",
+                description: format!("Set transfer fee of mint {} to {} basis points (max {})", mint, transfer_fee_basis_points, maximum_fee),
+                info: json!({
+                    "mint": mint,
+                    "transfer_fee_config_authority": authority,
+                    "transfer_fee_basis_points": transfer_fee_basis_points,
+                    "maximum_fee": maximum_fee,
+                    "multisig_signers": extra_signers(accounts, 2),
+                }),
+            }
+        }
+    };
+    
+    Ok(parsed)
+}
+
+fn decode_token_metadata_instruction(accounts: &[String], instruction: TokenMetadataInstruction) -> Result<Parsed> {
+    let metadata = account(accounts, 0)?;
+    
+    let parsed = match instruction {
+        TokenMetadataInstruction::Initialize(data) => {
+            let update_authority = account(accounts, 1)?;
+            let mint = account(accounts, 2)?;
+            let mint_authority = account(accounts, 3)?;
+            Parsed {
+                instruction_type: "InitializeTokenMetadata",
+                description: format!("Initialize metadata {} for mint {} as {} ({})", metadata, mint, data.name, data.symbol),
+                info: json!({
+                    "metadata": metadata,
+                    "update_authority": update_authority,
+                    "mint": mint,
+                    "mint_authority": mint_authority,
+                    "name": data.name,
+                    "symbol": data.symbol,
+                    "uri": data.uri,
+                }),
+            }
+        }
+        TokenMetadataInstruction::UpdateField(data) => {
+            let update_authority = account(accounts, 1)?;
+            let field = match data.field {
+                Field::Name => "name".to_string(),
+                Field::Symbol => "symbol".to_string(),
+                Field::Uri => "uri".to_string(),
+                Field::Key(key) => key,
+            };
+            Parsed {
+                instruction_type: "UpdateTokenMetadataField",
+                description: format!("Set metadata field {} of {} to {}", field, metadata, data.value),
+                info: json!({
+                    "metadata": metadata,
+                    "update_authority": update_authority,
+                    "field": field,
+                    "value": data.value,
+                }),
+            }
+        }
+        TokenMetadataInstruction::RemoveKey(data) => {
+            let update_authority = account(accounts, 1)?;
+            Parsed {
+                instruction_type: "RemoveTokenMetadataKey",
+                description: format!("Remove metadata key {} from {}", data.key, metadata),
+                info: json!({
+                    "metadata": metadata,
+                    "update_authority": update_authority,
+                    "key": data.key,
+                    "idempotent": data.idempotent,
+                }),
+            }
+        }
+        TokenMetadataInstruction::UpdateAuthority(data) => {
+            let update_authority = account(accounts, 1)?;
+            let new_authority: Option<Pubkey> = data.new_authority.into();
+            Parsed {
+                instruction_type: "UpdateTokenMetadataAuthority",
+                description: match new_authority {
+                    Some(key) => format!("Set update authority of metadata {} to {}", metadata, key),
+                    None => format!("Remove update authority of metadata {}", metadata),
+                },
+                info: json!({
+                    "metadata": metadata,
+                    "update_authority": update_authority,
+                    "new_authority": new_authority.map(|key| key.to_string()),
+                }),
+            }
+        }
+        TokenMetadataInstruction::Emit(data) => Parsed {
+            instruction_type: "EmitTokenMetadata",
+            description: format!("Emit metadata {}", metadata),
+            info: json!({
+                "metadata": metadata,
+                "start": data.start,
+                "end": data.end,
+            }),
+        },
+    };
+    
+    Ok(parsed)
+}
+
+fn decode_ata_instruction(accounts: &[String], data: &[u8]) -> Result<Parsed> {
+    let instruction_type = match data.first() {
+        None | Some(0) => "Create",
+        Some(1) => "CreateIdempotent",
+        Some(2) => "RecoverNested",
+        Some(_) => return Err(anyhow!("Invalid associated token account instruction data")),
+    };
+    
+    if instruction_type == "RecoverNested" {
+        let nested_account = account(accounts, 0)?;
+        let destination = account(accounts, 2)?;
+        let wallet = account(accounts, 5)?;
+        return Ok(Parsed {
+            instruction_type,
+            description: format!("Recover nested token account {} of wallet {} into {}", nested_account, wallet, destination),
+            info: json!({
+                "nested_account": nested_account,
+                "nested_mint": account(accounts, 1)?,
+                "destination": destination,
+                "owner_account": account(accounts, 3)?,
+                "owner_mint": account(accounts, 4)?,
+                "wallet": wallet,
+                "token_program": account(accounts, 6)?,
+            }),
+        });
+    }
+    
+    let payer = account(accounts, 0)?;
+    let associated_account = account(accounts, 1)?;
+    let wallet = account(accounts, 2)?;
+    let mint = account(accounts, 3)?;
+    Ok(Parsed {
+        instruction_type,
+        description: format!("Create associated token account {} for wallet {} and mint {}, paid by {}", associated_account, wallet, mint, payer),
+        info: json!({
+            "payer": payer,
+            "account": associated_account,
+            "wallet": wallet,
+            "mint": mint,
+            "token_program": account(accounts, 5)?,
+        }),
+    })
+}
+
+fn decode_memo_instruction(accounts: &[String], data: &[u8]) -> Result<Parsed> {
+    let memo = std::str::from_utf8(data)
+        .map_err(|_| anyhow!("Memo is not valid UTF-8"))?;
+    
+    Ok(Parsed {
+        instruction_type: "Memo",
+        description: format!("Memo: {}", memo),
+        info: json!({
+            "memo": memo,
+            "signers": accounts,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MintExtension, TokenMetadataFields};
+    use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn decode(spec: &InstructionResponse) -> DecodedInstruction {
+        let decoded = decode_instruction_response(spec).unwrap();
+        assert_eq!(decoded.error, None, "failed to decode {}", decoded.instruction_type);
+        decoded
+    }
+
+    fn ata(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> String {
+        get_associated_token_address_with_program_id(owner, mint, token_program).to_string()
+    }
+
+    #[test]
+    fn decodes_sol_transfer() {
+        let spec = solana_ops::create_sol_transfer_instruction(&key(1).to_string(), &key(2).to_string(), 5_000).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "system");
+        assert_eq!(decoded.instruction_type, "Transfer");
+        assert_eq!(decoded.info, json!({
+            "from": key(1).to_string(),
+            "to": key(2).to_string(),
+            "lamports": 5_000,
+        }));
+    }
+
+    #[test]
+    fn decodes_token_transfer_with_multisig_signers() {
+        let signers = vec![key(4).to_string(), key(5).to_string()];
+        let spec = solana_ops::create_token_transfer_instruction(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            &key(3).to_string(),
+            42,
+            None,
+            &signers,
+            None,
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "spl-token");
+        assert_eq!(decoded.instruction_type, "Transfer");
+        assert_eq!(decoded.info, json!({
+            "source": ata(&key(2), &key(1), &spl_token::ID),
+            "destination": ata(&key(3), &key(1), &spl_token::ID),
+            "owner": key(2).to_string(),
+            "amount": 42,
+            "multisig_signers": signers,
+        }));
+    }
+
+    #[test]
+    fn decodes_token_2022_transfer_checked() {
+        let spec = solana_ops::create_token_transfer_instruction(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            &key(3).to_string(),
+            1_500,
+            Some(6),
+            &[],
+            Some("token-2022"),
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "spl-token-2022");
+        assert_eq!(decoded.instruction_type, "TransferChecked");
+        assert_eq!(decoded.info, json!({
+            "source": ata(&key(2), &key(1), &spl_token_2022::ID),
+            "mint": key(1).to_string(),
+            "destination": ata(&key(3), &key(1), &spl_token_2022::ID),
+            "owner": key(2).to_string(),
+            "amount": 1_500,
+            "decimals": 6,
+            "multisig_signers": [],
+        }));
+    }
+
+    #[test]
+    fn decodes_mint_to() {
+        let spec = solana_ops::create_mint_to_instruction(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            &key(3).to_string(),
+            1_000,
+            None,
+            &[],
+            None,
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.instruction_type, "MintTo");
+        assert_eq!(decoded.info["mint"], key(1).to_string());
+        assert_eq!(decoded.info["destination"], key(2).to_string());
+        assert_eq!(decoded.info["mint_authority"], key(3).to_string());
+        assert_eq!(decoded.info["amount"], 1_000);
+    }
+
+    #[test]
+    fn decodes_set_authority() {
+        let spec = solana_ops::create_set_authority_instruction(
+            &key(1).to_string(),
+            "MintTokens",
+            &key(2).to_string(),
+            Some(&key(3).to_string()),
+            &[],
+            None,
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.instruction_type, "SetAuthority");
+        assert_eq!(decoded.info, json!({
+            "account": key(1).to_string(),
+            "authority": key(2).to_string(),
+            "authority_type": "MintTokens",
+            "new_authority": key(3).to_string(),
+            "multisig_signers": [],
+        }));
+    }
+
+    #[test]
+    fn decodes_token_2022_authority_revocation() {
+        let spec = solana_ops::create_set_authority_instruction(
+            &key(1).to_string(),
+            "TransferFeeConfig",
+            &key(2).to_string(),
+            None,
+            &[],
+            Some("token-2022"),
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "spl-token-2022");
+        assert_eq!(decoded.info["authority_type"], "TransferFeeConfig");
+        assert_eq!(decoded.info["new_authority"], Value::Null);
+    }
+
+    #[test]
+    fn decodes_token_2022_mint_with_extensions() {
+        let extensions = vec![
+            MintExtension::TransferFeeConfig {
+                transfer_fee_config_authority: Some(key(4).to_string()),
+                withdraw_withheld_authority: None,
+                transfer_fee_basis_points: 50,
+                maximum_fee: 5_000,
+            },
+            MintExtension::InterestBearing { rate_authority: None, rate: -25 },
+            MintExtension::MetadataPointer {
+                authority: None,
+                metadata_address: None,
+                metadata: Some(TokenMetadataFields {
+                    name: "Name".to_string(),
+                    symbol: "SYM".to_string(),
+                    uri: "https://example.com".to_string(),
+                    update_authority: None,
+                    additional_metadata: Vec::new(),
+                }),
+            },
+        ];
+        let created = solana_ops::create_mint_account_instructions(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            &key(3).to_string(),
+            6,
+            None,
+            &extensions,
+            1_000_000,
+            Some("token-2022"),
+        ).unwrap();
+        let decoded: Vec<DecodedInstruction> = created.instructions.iter().map(decode).collect();
+        
+        let types: Vec<&str> = decoded.iter().map(|decoded| decoded.instruction_type.as_str()).collect();
+        assert_eq!(types, vec![
+            "CreateAccount",
+            "InitializeTransferFeeConfig",
+            "InitializeInterestBearingConfig",
+            "InitializeMetadataPointer",
+            "InitializeMint2",
+            "InitializeTokenMetadata",
+        ]);
+        
+        assert_eq!(decoded[1].info, json!({
+            "mint": key(3).to_string(),
+            "transfer_fee_config_authority": key(4).to_string(),
+            "withdraw_withheld_authority": null,
+            "transfer_fee_basis_points": 50,
+            "maximum_fee": 5_000,
+        }));
+        assert_eq!(decoded[2].info["rate"], -25);
+        assert_eq!(decoded[3].info["metadata_address"], key(3).to_string());
+        assert_eq!(decoded[5].info["metadata"], key(3).to_string());
+        assert_eq!(decoded[5].info["mint_authority"], key(2).to_string());
+        assert_eq!(decoded[5].info["name"], "Name");
+    }
+
+    #[test]
+    fn decodes_memo() {
+        let spec = solana_ops::create_memo_instruction("invoice 42", &[key(1).to_string()]).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "spl-memo");
+        assert_eq!(decoded.instruction_type, "Memo");
+        assert_eq!(decoded.info, json!({
+            "memo": "invoice 42",
+            "signers": [key(1).to_string()],
+        }));
+    }
+
+    #[test]
+    fn decodes_idempotent_ata_creation() {
+        let spec = solana_ops::create_associated_token_account_instruction(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            &key(3).to_string(),
+            Some("token-2022"),
+        ).unwrap();
+        let decoded = decode(&spec);
+        
+        assert_eq!(decoded.program, "spl-associated-token-account");
+        assert_eq!(decoded.instruction_type, "CreateIdempotent");
+        assert_eq!(decoded.info, json!({
+            "payer": key(1).to_string(),
+            "account": ata(&key(2), &key(3), &spl_token_2022::ID),
+            "wallet": key(2).to_string(),
+            "mint": key(3).to_string(),
+            "token_program": spl_token_2022::ID.to_string(),
+        }));
+    }
+
+    #[test]
+    fn reports_unsupported_programs() {
+        let decoded = decode_instruction(&key(9), &[key(1).to_string()], &[1, 2, 3]);
+        
+        assert_eq!(decoded.program, "unknown");
+        assert_eq!(decoded.instruction_type, "Unknown");
+        assert!(decoded.error.is_some());
+        assert_eq!(decoded.info["data"], "AQID");
+    }
+}
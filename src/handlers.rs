@@ -1,7 +1,23 @@
 use warp::{Rejection, Reply};
+use solana_sdk::{nonce::State as NonceState, program_pack::Pack, stake::state::StakeStateV2};
 use crate::types::*;
 use crate::crypto;
 use crate::solana_ops;
+use crate::transaction;
+use crate::decoder;
+use crate::metaplex;
+use crate::rpc::{self, RpcHandle};
+
+// Plain requests keep the original single-instruction response. Requests
+// using any optional instruction always get `{ "instructions": [...] }` in
+// execution order, whatever the number of instructions.
+fn instructions_value(mut instructions: Vec<InstructionResponse>, as_list: bool) -> serde_json::Value {
+    if !as_list && instructions.len() == 1 {
+        serde_json::to_value(instructions.remove(0)).unwrap()
+    } else {
+        serde_json::to_value(InstructionListResponse { instructions }).unwrap()
+    }
+}
 
 pub async fn handle_generate_keypair() -> Result<impl Reply, Rejection> {
     match crypto::generate_keypair() {
@@ -22,7 +38,7 @@ pub async fn handle_generate_keypair() -> Result<impl Reply, Rejection> {
     }
 }
 
-pub async fn handle_create_token(req: CreateTokenRequest) -> Result<impl Reply, Rejection> {
+pub async fn handle_create_token(req: CreateTokenRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
     if req.mint_authority.is_empty() || req.mint.is_empty() {
         let response = ApiResponse::error("Missing required fields".to_string());
         return Ok(warp::reply::with_status(
@@ -31,9 +47,53 @@ pub async fn handle_create_token(req: CreateTokenRequest) -> Result<impl Reply,
         ));
     }
     
-    match solana_ops::create_mint_instruction(&req.mint_authority, &req.mint, req.decimals) {
-        Ok(instruction) => {
-            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+    let freeze_authority = match &req.freeze_authority {
+        Some(explicit) => explicit.as_deref(),
+        None => Some(req.mint_authority.as_str()),
+    };
+    
+    if !req.extensions.is_empty() && !req.create_account {
+        let response = ApiResponse::error("Mint extensions require create_account".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let result = if req.create_account {
+        let payer = req.payer.as_deref().unwrap_or(&req.mint_authority);
+        let size = solana_ops::mint_account_size(req.token_program.as_deref(), &req.extensions)
+            .and_then(|space| Ok(space + solana_ops::mint_metadata_size(&req.extensions)?));
+        let lamports = match size {
+            Ok(size) => rpc::rent_exempt_minimum(&rpc, size).await,
+            Err(e) => Err(e),
+        };
+        match lamports {
+            Ok(lamports) => solana_ops::create_mint_account_instructions(
+                payer,
+                &req.mint_authority,
+                &req.mint,
+                req.decimals,
+                freeze_authority,
+                &req.extensions,
+                lamports,
+                req.token_program.as_deref(),
+            ).map(|created| serde_json::to_value(created).unwrap()),
+            Err(e) => Err(e),
+        }
+    } else {
+        solana_ops::create_mint_instruction(
+            &req.mint_authority,
+            &req.mint,
+            req.decimals,
+            freeze_authority,
+            req.token_program.as_deref(),
+        ).map(|instruction| serde_json::to_value(instruction).unwrap())
+    };
+    
+    match result {
+        Ok(data) => {
+            let response = ApiResponse::success(data);
             Ok(warp::reply::with_status(
                 warp::reply::json(&response),
                 warp::http::StatusCode::OK,
@@ -58,7 +118,18 @@ pub async fn handle_mint_token(req: MintTokenRequest) -> Result<impl Reply, Reje
         ));
     }
     
-    if req.amount == 0 {
+    let amount = match solana_ops::resolve_token_amount(req.amount, req.ui_amount.as_deref(), req.decimals) {
+        Ok(amount) => amount,
+        Err(e) => {
+            let response = ApiResponse::error(e.to_string());
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ));
+        }
+    };
+    
+    if amount == 0 {
         let response = ApiResponse::error("Amount must be greater than 0".to_string());
         return Ok(warp::reply::with_status(
             warp::reply::json(&response),
@@ -66,7 +137,15 @@ pub async fn handle_mint_token(req: MintTokenRequest) -> Result<impl Reply, Reje
         ));
     }
     
-    match solana_ops::create_mint_to_instruction(&req.mint, &req.destination, &req.authority, req.amount) {
+    match solana_ops::create_mint_to_instruction(
+        &req.mint,
+        &req.destination,
+        &req.authority,
+        amount,
+        req.decimals,
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
         Ok(instruction) => {
             let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
             Ok(warp::reply::with_status(
@@ -155,9 +234,26 @@ pub async fn handle_send_sol(req: SendSolRequest) -> Result<impl Reply, Rejectio
         ));
     }
     
-    match solana_ops::create_sol_transfer_instruction(&req.from, &req.to, req.lamports) {
-        Ok(instruction) => {
-            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+    let as_list = req.memo.is_some()
+        || req.compute_unit_limit.is_some()
+        || req.compute_unit_price_micro_lamports.is_some();
+    
+    let instructions = solana_ops::create_sol_transfer_instruction(&req.from, &req.to, req.lamports)
+        .and_then(|transfer| {
+            let mut instructions = vec![transfer];
+            if let Some(memo) = &req.memo {
+                instructions.push(solana_ops::create_memo_instruction(memo, std::slice::from_ref(&req.from))?);
+            }
+            solana_ops::with_compute_budget(
+                instructions,
+                req.compute_unit_limit,
+                req.compute_unit_price_micro_lamports,
+            )
+        });
+    
+    match instructions {
+        Ok(instructions) => {
+            let response = ApiResponse::success(instructions_value(instructions, as_list));
             Ok(warp::reply::with_status(
                 warp::reply::json(&response),
                 warp::http::StatusCode::OK,
@@ -182,7 +278,18 @@ pub async fn handle_send_token(req: SendTokenRequest) -> Result<impl Reply, Reje
         ));
     }
     
-    if req.amount == 0 {
+    let amount = match solana_ops::resolve_token_amount(req.amount, req.ui_amount.as_deref(), req.decimals) {
+        Ok(amount) => amount,
+        Err(e) => {
+            let response = ApiResponse::error(e.to_string());
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ));
+        }
+    };
+    
+    if amount == 0 {
         let response = ApiResponse::error("Amount must be greater than 0".to_string());
         return Ok(warp::reply::with_status(
             warp::reply::json(&response),
@@ -190,9 +297,50 @@ pub async fn handle_send_token(req: SendTokenRequest) -> Result<impl Reply, Reje
         ));
     }
     
-    match solana_ops::create_token_transfer_instruction(&req.mint, &req.owner, &req.destination, req.amount) {
-        Ok(instruction) => {
-            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+    let as_list = req.create_destination_ata
+        || req.memo.is_some()
+        || req.compute_unit_limit.is_some()
+        || req.compute_unit_price_micro_lamports.is_some();
+    
+    let instructions = solana_ops::create_token_transfer_instruction(
+        &req.mint,
+        &req.owner,
+        &req.destination,
+        amount,
+        req.decimals,
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ).and_then(|transfer| {
+        let mut instructions = Vec::new();
+        if req.create_destination_ata {
+            let payer = req.payer.as_deref().unwrap_or(&req.owner);
+            instructions.push(solana_ops::create_associated_token_account_instruction(
+                payer,
+                &req.destination,
+                &req.mint,
+                req.token_program.as_deref(),
+            )?);
+        }
+        instructions.push(transfer);
+        if let Some(memo) = &req.memo {
+            // A multisig owner cannot sign, so its signers vouch for the memo instead.
+            let memo_signers = if req.multisig_signers.is_empty() {
+                std::slice::from_ref(&req.owner)
+            } else {
+                req.multisig_signers.as_slice()
+            };
+            instructions.push(solana_ops::create_memo_instruction(memo, memo_signers)?);
+        }
+        solana_ops::with_compute_budget(
+            instructions,
+            req.compute_unit_limit,
+            req.compute_unit_price_micro_lamports,
+        )
+    });
+    
+    match instructions {
+        Ok(instructions) => {
+            let response = ApiResponse::success(instructions_value(instructions, as_list));
             Ok(warp::reply::with_status(
                 warp::reply::json(&response),
                 warp::http::StatusCode::OK,
@@ -207,3 +355,1887 @@ pub async fn handle_send_token(req: SendTokenRequest) -> Result<impl Reply, Reje
         }
     }
 }
+
+pub async fn handle_build_transaction(req: BuildTransactionRequest) -> Result<impl Reply, Rejection> {
+    if req.fee_payer.is_empty() || req.recent_blockhash.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match transaction::build_transaction(&req.instructions, &req.fee_payer, &req.recent_blockhash) {
+        Ok(built) => {
+            let response = ApiResponse::success(serde_json::to_value(built).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to build transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_build_versioned_transaction(req: BuildVersionedTransactionRequest) -> Result<impl Reply, Rejection> {
+    if req.fee_payer.is_empty() || req.recent_blockhash.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match transaction::build_versioned_transaction(
+        &req.instructions,
+        &req.fee_payer,
+        &req.recent_blockhash,
+        &req.lookup_tables,
+    ) {
+        Ok(built) => {
+            let response = ApiResponse::success(serde_json::to_value(built).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to build versioned transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_sign_transaction(req: SignTransactionRequest) -> Result<impl Reply, Rejection> {
+    if req.transaction.is_empty() || req.secrets.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match transaction::sign_transaction(&req.transaction, &req.secrets) {
+        Ok(signed) => {
+            let response = ApiResponse::success(serde_json::to_value(signed).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to sign transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_merge_transactions(req: MergeTransactionsRequest) -> Result<impl Reply, Rejection> {
+    if req.transactions.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match transaction::merge_transactions(&req.transactions) {
+        Ok(signed) => {
+            let response = ApiResponse::success(serde_json::to_value(signed).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to merge transactions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_decode_transaction(req: DecodeTransactionRequest) -> Result<impl Reply, Rejection> {
+    if req.transaction.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match transaction::explain_transaction(&req.transaction, req.encoding.as_deref()) {
+        Ok(decoded) => {
+            let response = ApiResponse::success(serde_json::to_value(decoded).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to decode transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_decode_instruction(req: InstructionResponse) -> Result<impl Reply, Rejection> {
+    if req.program_id.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match decoder::decode_instruction_response(&req) {
+        Ok(decoded) => {
+            let response = ApiResponse::success(serde_json::to_value(decoded).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to decode instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_send_transaction(req: SendTransactionRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.transaction.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match rpc::send_transaction(
+        &rpc,
+        &req.transaction,
+        req.skip_preflight,
+        req.max_retries,
+        req.wait_for_commitment.as_deref(),
+        req.timeout_seconds,
+    ).await {
+        Ok(sent) => {
+            let response = ApiResponse::success(serde_json::to_value(sent).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to send transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_transaction_status(
+    signature: String,
+    query: TransactionStatusQuery,
+    rpc: RpcHandle,
+) -> Result<impl Reply, Rejection> {
+    match rpc::get_signature_status(
+        &rpc,
+        &signature,
+        query.wait_for_commitment.as_deref(),
+        query.timeout_seconds,
+    ).await {
+        Ok(status) => {
+            let response = ApiResponse::success(serde_json::to_value(status).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get transaction status: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_balance(pubkey: String, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_balance(&rpc, &pubkey).await {
+        Ok(result) => {
+            let response = ApiResponse::success(serde_json::to_value(result).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get balance: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_account_info(pubkey: String, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_account_info(&rpc, &pubkey).await {
+        Ok(result) => {
+            let response = ApiResponse::success(serde_json::to_value(result).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get account info: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_token_accounts(owner: String, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_token_accounts(&rpc, &owner).await {
+        Ok(result) => {
+            let response = ApiResponse::success(serde_json::to_value(result).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get token accounts: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_token_supply(mint: String, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_token_supply(&rpc, &mint).await {
+        Ok(result) => {
+            let response = ApiResponse::success(serde_json::to_value(result).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get token supply: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_simulate_transaction(req: SimulateTransactionRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.transaction.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match rpc::simulate_transaction(
+        &rpc,
+        &req.transaction,
+        req.sig_verify,
+        req.replace_recent_blockhash,
+        &req.accounts,
+    ).await {
+        Ok(simulation) => {
+            let response = ApiResponse::success(serde_json::to_value(simulation).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to simulate transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_blockhash(rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_latest_blockhash(&rpc).await {
+        Ok(blockhash) => {
+            let response = ApiResponse::success(serde_json::to_value(blockhash).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to get latest blockhash: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_estimate_fees(req: EstimateFeesRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::estimate_fees(&rpc, req.transaction.as_deref(), req.message.as_deref()).await {
+        Ok(estimate) => {
+            let response = ApiResponse::success(serde_json::to_value(estimate).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to estimate fees: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_memo(req: MemoRequest) -> Result<impl Reply, Rejection> {
+    match solana_ops::create_memo_instruction(&req.memo, &req.signers) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create memo instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_compute_budget(req: ComputeBudgetRequest) -> Result<impl Reply, Rejection> {
+    match solana_ops::create_compute_budget_instructions(req.compute_unit_limit, req.compute_unit_price_micro_lamports) {
+        Ok(instructions) => {
+            let response = ApiResponse::success(serde_json::to_value(InstructionListResponse { instructions }).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create compute budget instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_ata(req: CreateAtaRequest) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.owner.is_empty() || req.mint.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_associated_token_account_instruction(
+        &req.payer,
+        &req.owner,
+        &req.mint,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create associated token account instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_burn_token(req: BurnTokenRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    if req.amount == 0 {
+        let response = ApiResponse::error("Amount must be greater than 0".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_burn_instruction(
+        &req.mint,
+        &req.owner,
+        req.account.as_deref(),
+        req.amount,
+        req.decimals,
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create burn instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_approve_token(req: ApproveTokenRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.owner.is_empty() || req.delegate.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    if req.amount == 0 {
+        let response = ApiResponse::error("Amount must be greater than 0".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_approve_instruction(
+        &req.mint,
+        &req.owner,
+        &req.delegate,
+        req.account.as_deref(),
+        req.amount,
+        req.decimals,
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create approve instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_revoke_token(req: RevokeTokenRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_revoke_instruction(
+        &req.mint,
+        &req.owner,
+        req.account.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create revoke instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_close_token_account(req: CloseTokenAccountRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.owner.is_empty() || req.destination.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_close_account_instruction(
+        &req.mint,
+        &req.owner,
+        &req.destination,
+        req.account.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create close account instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_freeze_account(req: FreezeAccountRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.freeze_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_freeze_account_instruction(
+        &req.mint,
+        &req.freeze_authority,
+        req.owner.as_deref(),
+        req.account.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create freeze instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_thaw_account(req: FreezeAccountRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.freeze_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_thaw_account_instruction(
+        &req.mint,
+        &req.freeze_authority,
+        req.owner.as_deref(),
+        req.account.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create thaw instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_set_authority(req: SetAuthorityRequest) -> Result<impl Reply, Rejection> {
+    if req.account.is_empty() || req.authority_type.is_empty() || req.current_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_set_authority_instruction(
+        &req.account,
+        &req.authority_type,
+        &req.current_authority,
+        req.new_authority.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create set authority instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_wrap_sol(req: WrapSolRequest) -> Result<impl Reply, Rejection> {
+    if req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    if req.lamports == 0 {
+        let response = ApiResponse::error("Amount must be greater than 0".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_wrap_sol_instructions(
+        &req.owner,
+        req.lamports,
+        req.payer.as_deref(),
+        req.token_program.as_deref(),
+    ) {
+        Ok(instructions) => {
+            let response = ApiResponse::success(serde_json::to_value(InstructionListResponse { instructions }).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create wrap SOL instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_unwrap_sol(req: UnwrapSolRequest) -> Result<impl Reply, Rejection> {
+    if req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_unwrap_sol_instruction(
+        &req.owner,
+        req.account.as_deref(),
+        req.destination.as_deref(),
+        &req.multisig_signers,
+        req.token_program.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create unwrap SOL instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_multisig(req: CreateMultisigRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.multisig.is_empty() || req.signers.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let result = match rpc::rent_exempt_minimum(&rpc, spl_token::state::Multisig::LEN).await {
+        Ok(lamports) => solana_ops::create_multisig_instructions(
+            &req.payer,
+            &req.multisig,
+            &req.signers,
+            req.m,
+            lamports,
+            req.token_program.as_deref(),
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create multisig instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_withdraw_withheld_fees(req: WithdrawWithheldFeesRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.destination.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_withdraw_withheld_fees_instruction(
+        &req.mint,
+        &req.destination,
+        &req.authority,
+        &req.sources,
+        &req.multisig_signers,
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create withdraw withheld fees instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_harvest_withheld_fees(req: HarvestWithheldFeesRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.sources.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_harvest_withheld_fees_instruction(&req.mint, &req.sources) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create harvest withheld fees instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_metadata(req: CreateMetadataRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.mint_authority.is_empty() || req.data.name.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match metaplex::create_metadata_instruction(
+        &req.mint,
+        &req.mint_authority,
+        req.payer.as_deref(),
+        req.update_authority.as_deref(),
+        &req.data,
+        req.is_mutable,
+    ) {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create metadata instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_update_metadata(req: UpdateMetadataRequest) -> Result<impl Reply, Rejection> {
+    if req.mint.is_empty() || req.update_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match metaplex::update_metadata_instruction(
+        &req.mint,
+        &req.update_authority,
+        req.data.as_ref(),
+        req.new_update_authority.as_deref(),
+        req.primary_sale_happened,
+        req.is_mutable,
+    ) {
+        Ok(updated) => {
+            let response = ApiResponse::success(serde_json::to_value(updated).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create update metadata instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_account(req: CreateAccountRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.new_account.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    // Reject oversized space before it reaches the rent computation.
+    let lamports = match req.lamports {
+        Some(lamports) => Ok(lamports),
+        None => match solana_ops::check_account_space(req.space) {
+            Ok(()) => rpc::rent_exempt_minimum(&rpc, req.space as usize).await,
+            Err(e) => Err(e),
+        },
+    };
+    
+    let result = match lamports {
+        Ok(lamports) => solana_ops::create_system_account_instruction(
+            &req.payer,
+            &req.new_account,
+            &req.owner,
+            req.space,
+            lamports,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create create account instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_account_with_seed(req: CreateAccountWithSeedRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.base.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    // Reject oversized space before it reaches the rent computation.
+    let lamports = match req.lamports {
+        Some(lamports) => Ok(lamports),
+        None => match solana_ops::check_account_space(req.space) {
+            Ok(()) => rpc::rent_exempt_minimum(&rpc, req.space as usize).await,
+            Err(e) => Err(e),
+        },
+    };
+    
+    let result = match lamports {
+        Ok(lamports) => solana_ops::create_account_with_seed_instruction(
+            &req.payer,
+            &req.base,
+            &req.seed,
+            &req.owner,
+            req.space,
+            lamports,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create create account with seed instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_allocate(req: AllocateRequest) -> Result<impl Reply, Rejection> {
+    if req.account.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_allocate_instruction(&req.account, req.space) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create allocate instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_assign(req: AssignRequest) -> Result<impl Reply, Rejection> {
+    if req.account.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_assign_instruction(&req.account, &req.owner) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create assign instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_transfer_with_seed(req: TransferWithSeedRequest) -> Result<impl Reply, Rejection> {
+    if req.from_base.is_empty() || req.from_owner.is_empty() || req.to.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_transfer_with_seed_instruction(
+        &req.from_base,
+        &req.from_seed,
+        &req.from_owner,
+        &req.to,
+        req.lamports,
+    ) {
+        Ok(seeded) => {
+            let response = ApiResponse::success(serde_json::to_value(seeded).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create transfer with seed instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_allocate_with_seed(req: AllocateWithSeedRequest) -> Result<impl Reply, Rejection> {
+    if req.base.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_allocate_with_seed_instruction(
+        &req.base,
+        &req.seed,
+        &req.owner,
+        req.space,
+    ) {
+        Ok(seeded) => {
+            let response = ApiResponse::success(serde_json::to_value(seeded).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create allocate with seed instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_assign_with_seed(req: AssignWithSeedRequest) -> Result<impl Reply, Rejection> {
+    if req.base.is_empty() || req.owner.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_assign_with_seed_instruction(&req.base, &req.seed, &req.owner) {
+        Ok(seeded) => {
+            let response = ApiResponse::success(serde_json::to_value(seeded).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create assign with seed instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_nonce_account(req: CreateNonceAccountRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.nonce_account.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let lamports = match req.lamports {
+        Some(lamports) => Ok(lamports),
+        None => rpc::rent_exempt_minimum(&rpc, NonceState::size()).await,
+    };
+    
+    let result = match lamports {
+        Ok(lamports) => solana_ops::create_nonce_account_instructions(
+            &req.payer,
+            &req.nonce_account,
+            req.authority.as_deref(),
+            lamports,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create nonce account instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_advance_nonce(req: AdvanceNonceRequest) -> Result<impl Reply, Rejection> {
+    if req.nonce_account.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_advance_nonce_instruction(&req.nonce_account, &req.authority) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create advance nonce instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_withdraw_nonce(req: WithdrawNonceRequest) -> Result<impl Reply, Rejection> {
+    if req.nonce_account.is_empty() || req.authority.is_empty() || req.to.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_withdraw_nonce_instruction(
+        &req.nonce_account,
+        &req.authority,
+        &req.to,
+        req.lamports,
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create withdraw nonce instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_authorize_nonce(req: AuthorizeNonceRequest) -> Result<impl Reply, Rejection> {
+    if req.nonce_account.is_empty() || req.authority.is_empty() || req.new_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_authorize_nonce_instruction(
+        &req.nonce_account,
+        &req.authority,
+        &req.new_authority,
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create authorize nonce instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_get_nonce_account(nonce_account: String, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    match rpc::get_nonce_account(&rpc, &nonce_account).await {
+        Ok(nonce) => {
+            let response = ApiResponse::success(serde_json::to_value(nonce).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to fetch nonce account: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_build_nonce_transaction(req: BuildNonceTransactionRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.fee_payer.is_empty() || req.nonce_account.is_empty() || req.nonce_authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    // Without an explicit nonce value the current one is read from the account.
+    let nonce = match &req.nonce {
+        Some(nonce) => Ok(nonce.clone()),
+        None => rpc::get_nonce_account(&rpc, &req.nonce_account).await.map(|account| account.nonce),
+    };
+    
+    let result = match nonce {
+        Ok(nonce) => transaction::build_nonce_transaction(
+            &req.instructions,
+            &req.fee_payer,
+            &req.nonce_account,
+            &req.nonce_authority,
+            &nonce,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(built) => {
+            let response = ApiResponse::success(serde_json::to_value(built).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to build nonce transaction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_stake_account(req: CreateStakeAccountRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.payer.is_empty() || req.stake_account.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let result = match rpc::rent_exempt_minimum(&rpc, StakeStateV2::size_of()).await {
+        Ok(rent_exempt_reserve) => solana_ops::create_stake_account_instructions(
+            &req.payer,
+            &req.stake_account,
+            req.staker.as_deref(),
+            req.withdrawer.as_deref(),
+            req.lockup.as_ref(),
+            req.lamports,
+            rent_exempt_reserve,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create stake account instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_delegate_stake(req: DelegateStakeRequest) -> Result<impl Reply, Rejection> {
+    if req.stake_account.is_empty() || req.staker.is_empty() || req.vote_account.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_delegate_stake_instruction(&req.stake_account, &req.staker, &req.vote_account) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create delegate stake instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_deactivate_stake(req: DeactivateStakeRequest) -> Result<impl Reply, Rejection> {
+    if req.stake_account.is_empty() || req.staker.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_deactivate_stake_instruction(&req.stake_account, &req.staker) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create deactivate stake instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_withdraw_stake(req: WithdrawStakeRequest) -> Result<impl Reply, Rejection> {
+    if req.stake_account.is_empty() || req.withdrawer.is_empty() || req.to.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_withdraw_stake_instruction(
+        &req.stake_account,
+        &req.withdrawer,
+        &req.to,
+        req.lamports,
+        req.custodian.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create withdraw stake instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_split_stake(req: SplitStakeRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.stake_account.is_empty() || req.staker.is_empty() || req.split_stake_account.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let result = match rpc::rent_exempt_minimum(&rpc, StakeStateV2::size_of()).await {
+        Ok(rent_exempt_reserve) => solana_ops::create_split_stake_instructions(
+            &req.stake_account,
+            &req.staker,
+            &req.split_stake_account,
+            req.lamports,
+            req.payer.as_deref(),
+            rent_exempt_reserve,
+        ),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(instructions) => {
+            let response = ApiResponse::success(serde_json::to_value(InstructionListResponse { instructions }).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create split stake instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_merge_stake(req: MergeStakeRequest) -> Result<impl Reply, Rejection> {
+    if req.destination_stake_account.is_empty() || req.source_stake_account.is_empty() || req.staker.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_merge_stake_instructions(&req.destination_stake_account, &req.source_stake_account, &req.staker) {
+        Ok(instructions) => {
+            let response = ApiResponse::success(serde_json::to_value(InstructionListResponse { instructions }).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create merge stake instructions: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_authorize_stake(req: AuthorizeStakeRequest) -> Result<impl Reply, Rejection> {
+    if req.stake_account.is_empty() || req.authority.is_empty() || req.new_authority.is_empty() || req.stake_authorize.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_authorize_stake_instruction(
+        &req.stake_account,
+        &req.authority,
+        &req.new_authority,
+        &req.stake_authorize,
+        req.custodian.as_deref(),
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create authorize stake instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_create_lookup_table(req: CreateLookupTableRequest, rpc: RpcHandle) -> Result<impl Reply, Rejection> {
+    if req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    let recent_slot = match req.recent_slot {
+        Some(slot) => Ok(slot),
+        None => rpc::get_lookup_table_slot(&rpc).await,
+    };
+    
+    let result = match recent_slot {
+        Ok(slot) => solana_ops::create_lookup_table_instruction(&req.authority, req.payer.as_deref(), slot),
+        Err(e) => Err(e),
+    };
+    
+    match result {
+        Ok(created) => {
+            let response = ApiResponse::success(serde_json::to_value(created).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create lookup table instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_extend_lookup_table(req: ExtendLookupTableRequest) -> Result<impl Reply, Rejection> {
+    if req.lookup_table.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_extend_lookup_table_instruction(
+        &req.lookup_table,
+        &req.authority,
+        req.payer.as_deref(),
+        &req.addresses,
+    ) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create extend lookup table instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_freeze_lookup_table(req: FreezeLookupTableRequest) -> Result<impl Reply, Rejection> {
+    if req.lookup_table.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_freeze_lookup_table_instruction(&req.lookup_table, &req.authority) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create freeze lookup table instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_deactivate_lookup_table(req: DeactivateLookupTableRequest) -> Result<impl Reply, Rejection> {
+    if req.lookup_table.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_deactivate_lookup_table_instruction(&req.lookup_table, &req.authority) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create deactivate lookup table instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+pub async fn handle_close_lookup_table(req: CloseLookupTableRequest) -> Result<impl Reply, Rejection> {
+    if req.lookup_table.is_empty() || req.authority.is_empty() {
+        let response = ApiResponse::error("Missing required fields".to_string());
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&response),
+            warp::http::StatusCode::BAD_REQUEST,
+        ));
+    }
+    
+    match solana_ops::create_close_lookup_table_instruction(&req.lookup_table, &req.authority, req.recipient.as_deref()) {
+        Ok(instruction) => {
+            let response = ApiResponse::success(serde_json::to_value(instruction).unwrap());
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::OK,
+            ))
+        }
+        Err(e) => {
+            let response = ApiResponse::error(format!("Failed to create close lookup table instruction: {}", e));
+            Ok(warp::reply::with_status(
+                warp::reply::json(&response),
+                warp::http::StatusCode::BAD_REQUEST,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use solana_sdk::pubkey::Pubkey;
+
+    async fn response_data(reply: impl Reply) -> Value {
+        let response = reply.into_response();
+        assert_eq!(response.status(), warp::http::StatusCode::OK);
+        let body = warp::hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        body["data"].clone()
+    }
+
+    fn send_sol_request(options: Value) -> SendSolRequest {
+        let mut request = json!({
+            "from": Pubkey::new_unique().to_string(),
+            "to": Pubkey::new_unique().to_string(),
+            "lamports": 1_000,
+        });
+        request.as_object_mut().unwrap().extend(options.as_object().unwrap().clone());
+        serde_json::from_value(request).unwrap()
+    }
+
+    fn send_token_request(options: Value) -> SendTokenRequest {
+        let mut request = json!({
+            "destination": Pubkey::new_unique().to_string(),
+            "mint": Pubkey::new_unique().to_string(),
+            "owner": Pubkey::new_unique().to_string(),
+            "amount": 1_000,
+        });
+        request.as_object_mut().unwrap().extend(options.as_object().unwrap().clone());
+        serde_json::from_value(request).unwrap()
+    }
+
+    fn instruction_programs(data: &Value) -> Vec<String> {
+        data["instructions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|instruction| instruction["program_id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn plain_sol_transfer_returns_a_single_instruction() {
+        let data = response_data(handle_send_sol(send_sol_request(json!({}))).await.unwrap()).await;
+        
+        assert!(data.get("instructions").is_none());
+        assert_eq!(data["program_id"], solana_sdk::system_program::ID.to_string());
+    }
+
+    #[tokio::test]
+    async fn sol_transfer_options_return_an_instruction_list() {
+        let data = response_data(handle_send_sol(send_sol_request(json!({ "memo": "rent" }))).await.unwrap()).await;
+        assert_eq!(instruction_programs(&data), vec![solana_sdk::system_program::ID.to_string(), spl_memo::ID.to_string()]);
+        
+        let request = send_sol_request(json!({ "compute_unit_price_micro_lamports": 1_000 }));
+        let data = response_data(handle_send_sol(request).await.unwrap()).await;
+        assert_eq!(instruction_programs(&data), vec![
+            solana_sdk::compute_budget::ID.to_string(),
+            solana_sdk::system_program::ID.to_string(),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn plain_token_transfer_returns_a_single_instruction() {
+        let data = response_data(handle_send_token(send_token_request(json!({}))).await.unwrap()).await;
+        
+        assert!(data.get("instructions").is_none());
+        assert_eq!(data["program_id"], spl_token::ID.to_string());
+    }
+
+    #[tokio::test]
+    async fn token_transfer_options_return_an_instruction_list() {
+        let request = send_token_request(json!({ "create_destination_ata": true }));
+        let data = response_data(handle_send_token(request).await.unwrap()).await;
+        assert_eq!(instruction_programs(&data), vec![
+            spl_associated_token_account::ID.to_string(),
+            spl_token::ID.to_string(),
+        ]);
+        
+        let request = send_token_request(json!({ "memo": "invoice 7", "compute_unit_limit": 50_000 }));
+        let data = response_data(handle_send_token(request).await.unwrap()).await;
+        assert_eq!(instruction_programs(&data), vec![
+            solana_sdk::compute_budget::ID.to_string(),
+            spl_token::ID.to_string(),
+            spl_memo::ID.to_string(),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn mint_creation_funds_rent_offline_without_rpc() {
+        let request: CreateTokenRequest = serde_json::from_value(json!({
+            "mintAuthority": Pubkey::new_unique().to_string(),
+            "mint": Pubkey::new_unique().to_string(),
+            "decimals": 9,
+            "create_account": true,
+        })).unwrap();
+        let data = response_data(handle_create_token(request, None).await.unwrap()).await;
+        
+        assert_eq!(data["space"], spl_token::state::Mint::LEN);
+        assert_eq!(data["lamports"], solana_sdk::rent::Rent::default().minimum_balance(spl_token::state::Mint::LEN));
+        assert_eq!(data["instructions"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn mint_creation_funds_embedded_metadata() {
+        let extensions = json!([
+            { "type": "non_transferable" },
+            { "type": "metadata_pointer", "metadata": { "name": "Coin", "symbol": "CN", "uri": "https://example.com/coin" } },
+        ]);
+        let request: CreateTokenRequest = serde_json::from_value(json!({
+            "mintAuthority": Pubkey::new_unique().to_string(),
+            "mint": Pubkey::new_unique().to_string(),
+            "decimals": 6,
+            "create_account": true,
+            "token_program": "token-2022",
+            "extensions": extensions,
+        })).unwrap();
+        let extensions: Vec<MintExtension> = serde_json::from_value(extensions).unwrap();
+        let space = solana_ops::mint_account_size(Some("token-2022"), &extensions).unwrap();
+        let metadata = solana_ops::mint_metadata_size(&extensions).unwrap();
+        
+        let data = response_data(handle_create_token(request, None).await.unwrap()).await;
+        assert_eq!(data["space"], space);
+        assert_eq!(data["lamports"], solana_sdk::rent::Rent::default().minimum_balance(space + metadata));
+    }
+}
@@ -1,10 +1,13 @@
 use warp::Filter;
-use std::collections::HashMap;
 
 mod handlers;
 mod types;
 mod crypto;
 mod solana_ops;
+mod transaction;
+mod decoder;
+mod rpc;
+mod metaplex;
 
 use handlers::*;
 use types::*;
@@ -16,6 +19,10 @@ async fn main() {
         .allow_headers(vec!["content-type"])
         .allow_methods(vec!["POST", "GET", "OPTIONS"]);
 
+    let rpc = rpc::client_from_env();
+    let rpc_url = rpc.as_ref().map(|client| client.url());
+    let with_rpc = warp::any().map(move || rpc.clone());
+
     let health = warp::path("health")
         .and(warp::get())
         .map(|| {
@@ -32,6 +39,7 @@ async fn main() {
     let create_token = warp::path!("token" / "create")
         .and(warp::post())
         .and(warp::body::json())
+        .and(with_rpc.clone())
         .and_then(handle_create_token);
 
     let mint_token = warp::path!("token" / "mint")
@@ -39,6 +47,208 @@ async fn main() {
         .and(warp::body::json())
         .and_then(handle_mint_token);
 
+    let create_ata = warp::path!("token" / "ata" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_create_ata);
+
+    let burn_token = warp::path!("token" / "burn")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_burn_token);
+
+    let approve_token = warp::path!("token" / "approve")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_approve_token);
+
+    let revoke_token = warp::path!("token" / "revoke")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_revoke_token);
+
+    let close_token_account = warp::path!("token" / "close")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_close_token_account);
+
+    let freeze_account = warp::path!("token" / "freeze")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_freeze_account);
+
+    let thaw_account = warp::path!("token" / "thaw")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_thaw_account);
+
+    let set_authority = warp::path!("token" / "set-authority")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_set_authority);
+
+    let wrap_sol = warp::path!("token" / "wrap-sol")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_wrap_sol);
+
+    let unwrap_sol = warp::path!("token" / "unwrap-sol")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_unwrap_sol);
+
+    let create_multisig = warp::path!("token" / "multisig" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_multisig);
+
+    let withdraw_withheld_fees = warp::path!("token" / "fees" / "withdraw")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_withdraw_withheld_fees);
+
+    let harvest_withheld_fees = warp::path!("token" / "fees" / "harvest")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_harvest_withheld_fees);
+
+    let create_metadata = warp::path!("token" / "metadata" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_create_metadata);
+
+    let update_metadata = warp::path!("token" / "metadata" / "update")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_update_metadata);
+
+    let create_account = warp::path!("system" / "create-account")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_account);
+
+    let create_account_with_seed = warp::path!("system" / "create-account-with-seed")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_account_with_seed);
+
+    let allocate = warp::path!("system" / "allocate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_allocate);
+
+    let assign = warp::path!("system" / "assign")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_assign);
+
+    let transfer_with_seed = warp::path!("system" / "transfer-with-seed")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_transfer_with_seed);
+
+    let allocate_with_seed = warp::path!("system" / "allocate-with-seed")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_allocate_with_seed);
+
+    let assign_with_seed = warp::path!("system" / "assign-with-seed")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_assign_with_seed);
+
+    let create_nonce_account = warp::path!("nonce" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_nonce_account);
+
+    let advance_nonce = warp::path!("nonce" / "advance")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_advance_nonce);
+
+    let withdraw_nonce = warp::path!("nonce" / "withdraw")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_withdraw_nonce);
+
+    let authorize_nonce = warp::path!("nonce" / "authorize")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_authorize_nonce);
+
+    let get_nonce_account = warp::path!("nonce" / String)
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_nonce_account);
+
+    let create_stake_account = warp::path!("stake" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_stake_account);
+
+    let delegate_stake = warp::path!("stake" / "delegate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_delegate_stake);
+
+    let deactivate_stake = warp::path!("stake" / "deactivate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_deactivate_stake);
+
+    let withdraw_stake = warp::path!("stake" / "withdraw")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_withdraw_stake);
+
+    let split_stake = warp::path!("stake" / "split")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_split_stake);
+
+    let merge_stake = warp::path!("stake" / "merge")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_merge_stake);
+
+    let authorize_stake = warp::path!("stake" / "authorize")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_authorize_stake);
+
+    let create_lookup_table = warp::path!("lookup-table" / "create")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_create_lookup_table);
+
+    let extend_lookup_table = warp::path!("lookup-table" / "extend")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_extend_lookup_table);
+
+    let freeze_lookup_table = warp::path!("lookup-table" / "freeze")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_freeze_lookup_table);
+
+    let deactivate_lookup_table = warp::path!("lookup-table" / "deactivate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_deactivate_lookup_table);
+
+    let close_lookup_table = warp::path!("lookup-table" / "close")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_close_lookup_table);
+
     let sign_message = warp::path!("message" / "sign")
         .and(warp::post())
         .and(warp::body::json())
@@ -59,14 +269,184 @@ async fn main() {
         .and(warp::body::json())
         .and_then(handle_send_token);
 
-    let routes = health
-        .or(keypair)
-        .or(create_token)
+    let memo = warp::path!("memo")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_memo);
+
+    let compute_budget = warp::path!("compute-budget")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_compute_budget);
+
+    let build_transaction = warp::path!("transaction" / "build")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_build_transaction);
+
+    let build_versioned_transaction = warp::path!("transaction" / "build" / "v0")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_build_versioned_transaction);
+
+    let build_nonce_transaction = warp::path!("transaction" / "build" / "nonce")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_build_nonce_transaction);
+
+    let sign_transaction = warp::path!("transaction" / "sign")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_sign_transaction);
+
+    let merge_transactions = warp::path!("transaction" / "merge")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_merge_transactions);
+
+    let decode_transaction = warp::path!("transaction" / "decode")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_decode_transaction);
+
+    let decode_instruction = warp::path!("instruction" / "decode")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(handle_decode_instruction);
+
+    let send_transaction = warp::path!("transaction" / "send")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_send_transaction);
+
+    let simulate_transaction = warp::path!("transaction" / "simulate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_simulate_transaction);
+
+    let transaction_status = warp::path!("transaction" / String / "status")
+        .and(warp::get())
+        .and(warp::query::<TransactionStatusQuery>())
+        .and(with_rpc.clone())
+        .and_then(handle_transaction_status);
+
+    let get_balance = warp::path!("account" / String / "balance")
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_balance);
+
+    let get_token_accounts = warp::path!("account" / String / "tokens")
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_token_accounts);
+
+    let get_account_info = warp::path!("account" / String)
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_account_info);
+
+    let get_token_supply = warp::path!("token" / String / "supply")
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_token_supply);
+
+    let get_blockhash = warp::path!("blockhash")
+        .and(warp::get())
+        .and(with_rpc.clone())
+        .and_then(handle_get_blockhash);
+
+    let estimate_fees = warp::path!("fees" / "estimate")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_rpc.clone())
+        .and_then(handle_estimate_fees);
+
+    let token_routes = create_token
         .or(mint_token)
-        .or(sign_message)
+        .or(create_ata)
+        .or(burn_token)
+        .or(approve_token)
+        .or(revoke_token)
+        .or(close_token_account)
+        .or(freeze_account)
+        .or(thaw_account)
+        .or(set_authority)
+        .or(wrap_sol)
+        .or(unwrap_sol)
+        .or(create_multisig)
+        .or(withdraw_withheld_fees)
+        .or(harvest_withheld_fees)
+        .or(create_metadata)
+        .or(update_metadata)
+        .boxed();
+
+    let system_routes = create_account
+        .or(create_account_with_seed)
+        .or(allocate)
+        .or(assign)
+        .or(transfer_with_seed)
+        .or(allocate_with_seed)
+        .or(assign_with_seed)
+        .or(create_nonce_account)
+        .or(advance_nonce)
+        .or(withdraw_nonce)
+        .or(authorize_nonce)
+        .or(get_nonce_account)
+        .boxed();
+
+    let stake_routes = create_stake_account
+        .or(delegate_stake)
+        .or(deactivate_stake)
+        .or(withdraw_stake)
+        .or(split_stake)
+        .or(merge_stake)
+        .or(authorize_stake)
+        .boxed();
+
+    let lookup_table_routes = create_lookup_table
+        .or(extend_lookup_table)
+        .or(freeze_lookup_table)
+        .or(deactivate_lookup_table)
+        .or(close_lookup_table)
+        .boxed();
+
+    let transaction_routes = sign_message
         .or(verify_message)
         .or(send_sol)
         .or(send_token)
+        .or(memo)
+        .or(compute_budget)
+        .or(build_transaction)
+        .or(build_versioned_transaction)
+        .or(build_nonce_transaction)
+        .or(sign_transaction)
+        .or(merge_transactions)
+        .or(decode_transaction)
+        .or(decode_instruction)
+        .boxed();
+
+    let rpc_routes = send_transaction
+        .or(simulate_transaction)
+        .or(transaction_status)
+        .or(get_balance)
+        .or(get_token_accounts)
+        .or(get_account_info)
+        .or(get_token_supply)
+        .or(get_blockhash)
+        .or(estimate_fees)
+        .boxed();
+
+    let routes = health
+        .or(keypair)
+        .or(token_routes)
+        .or(system_routes)
+        .or(stake_routes)
+        .or(lookup_table_routes)
+        .or(transaction_routes)
+        .or(rpc_routes)
         .with(cors)
         .recover(handle_rejection);
 
@@ -77,6 +457,10 @@ async fn main() {
 
     println!("🚀 Solana HTTP Server starting on port {}", port);
     println!("🔗 Health check: http://localhost:{}/health", port);
+    match rpc_url {
+        Some(url) => println!("🌐 RPC endpoint: {}", url),
+        None => println!("🌐 RPC endpoint: not configured (set SOLANA_RPC_URL)"),
+    }
     
     warp::serve(routes)
         .run(([0, 0, 0, 0], port))
@@ -86,8 +470,10 @@ async fn main() {
 async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, std::convert::Infallible> {
     let error_response = if err.is_not_found() {
         ApiResponse::error("Endpoint not found".to_string())
-    } else if let Some(_) = err.find::<warp::filters::body::BodyDeserializeError>() {
+    } else if err.find::<warp::filters::body::BodyDeserializeError>().is_some() {
         ApiResponse::error("Invalid JSON in request body".to_string())
+    } else if err.find::<warp::reject::InvalidQuery>().is_some() {
+        ApiResponse::error("Invalid query parameters".to_string())
     } else {
         ApiResponse::error("Internal server error".to_string())
     };
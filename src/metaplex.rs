@@ -0,0 +1,443 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use anyhow::{Result, anyhow};
+use std::str::FromStr;
+use crate::types::{CreatorInput, MetadataDataInput, MetadataInstructionResponse};
+use crate::solana_ops::instruction_to_response;
+
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_URI_LENGTH: usize = 200;
+const MAX_CREATOR_LIMIT: usize = 5;
+
+// Metadata fields after validation, with creators resolved to pubkeys.
+struct MetadataData {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Vec<(Pubkey, bool, u8)>,
+    collection: Option<Pubkey>,
+}
+
+pub fn find_metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    ).0
+}
+
+pub fn create_metadata_instruction(
+    mint: &str,
+    mint_authority: &str,
+    payer: Option<&str>,
+    update_authority: Option<&str>,
+    data: &MetadataDataInput,
+    is_mutable: bool,
+) -> Result<MetadataInstructionResponse> {
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let mint_authority_pubkey = Pubkey::from_str(mint_authority)
+        .map_err(|_| anyhow!("Invalid mint authority address"))?;
+    
+    let payer_pubkey = match payer {
+        Some(payer) => Pubkey::from_str(payer)
+            .map_err(|_| anyhow!("Invalid payer address"))?,
+        None => mint_authority_pubkey,
+    };
+    
+    let update_authority_pubkey = match update_authority {
+        Some(authority) => Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid update authority address"))?,
+        None => mint_authority_pubkey,
+    };
+    
+    let data = parse_metadata_data(data)?;
+    // Only the update authority signs, so no other creator can be verified here.
+    if let Some((creator, _, _)) = data.creators
+        .iter()
+        .find(|(address, verified, _)| *verified && *address != update_authority_pubkey)
+    {
+        return Err(anyhow!("Creator {} can only be verified by its own signature", creator));
+    }
+    
+    let metadata = find_metadata_address(&mint_pubkey);
+    
+    let mut instruction_data = vec![CREATE_METADATA_ACCOUNT_V3];
+    write_data_v2(&mut instruction_data, &data);
+    write_bool(&mut instruction_data, is_mutable);
+    // collection_details
+    instruction_data.push(0);
+    
+    let instruction = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(mint_authority_pubkey, true),
+            AccountMeta::new(payer_pubkey, true),
+            AccountMeta::new_readonly(update_authority_pubkey, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data,
+    };
+    
+    Ok(MetadataInstructionResponse {
+        metadata: metadata.to_string(),
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+pub fn update_metadata_instruction(
+    mint: &str,
+    update_authority: &str,
+    data: Option<&MetadataDataInput>,
+    new_update_authority: Option<&str>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+) -> Result<MetadataInstructionResponse> {
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let update_authority_pubkey = Pubkey::from_str(update_authority)
+        .map_err(|_| anyhow!("Invalid update authority address"))?;
+    
+    let new_update_authority_pubkey = new_update_authority
+        .map(|authority| Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid new update authority address")))
+        .transpose()?;
+    
+    let data = data
+        .map(parse_metadata_data)
+        .transpose()?;
+    
+    if data.is_none() && new_update_authority_pubkey.is_none() && primary_sale_happened.is_none() && is_mutable.is_none() {
+        return Err(anyhow!("Nothing to update"));
+    }
+    
+    if primary_sale_happened == Some(false) {
+        return Err(anyhow!("primary_sale_happened can only be set to true"));
+    }
+    
+    if is_mutable == Some(true) {
+        return Err(anyhow!("is_mutable can only be set to false"));
+    }
+    
+    let metadata = find_metadata_address(&mint_pubkey);
+    
+    let mut instruction_data = vec![UPDATE_METADATA_ACCOUNT_V2];
+    match &data {
+        Some(data) => {
+            instruction_data.push(1);
+            write_data_v2(&mut instruction_data, data);
+        }
+        None => instruction_data.push(0),
+    }
+    match new_update_authority_pubkey {
+        Some(authority) => {
+            instruction_data.push(1);
+            instruction_data.extend_from_slice(authority.as_ref());
+        }
+        None => instruction_data.push(0),
+    }
+    write_optional_bool(&mut instruction_data, primary_sale_happened);
+    write_optional_bool(&mut instruction_data, is_mutable);
+    
+    let instruction = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(update_authority_pubkey, true),
+        ],
+        data: instruction_data,
+    };
+    
+    Ok(MetadataInstructionResponse {
+        metadata: metadata.to_string(),
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+fn parse_metadata_data(data: &MetadataDataInput) -> Result<MetadataData> {
+    if data.name.len() > MAX_NAME_LENGTH {
+        return Err(anyhow!("Name must be at most {} bytes", MAX_NAME_LENGTH));
+    }
+    
+    if data.symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(anyhow!("Symbol must be at most {} bytes", MAX_SYMBOL_LENGTH));
+    }
+    
+    if data.uri.len() > MAX_URI_LENGTH {
+        return Err(anyhow!("URI must be at most {} bytes", MAX_URI_LENGTH));
+    }
+    
+    if data.seller_fee_basis_points > 10_000 {
+        return Err(anyhow!("Seller fee basis points must be at most 10000"));
+    }
+    
+    let collection = data.collection
+        .as_deref()
+        .map(|collection| Pubkey::from_str(collection)
+            .map_err(|_| anyhow!("Invalid collection address")))
+        .transpose()?;
+    
+    Ok(MetadataData {
+        name: data.name.clone(),
+        symbol: data.symbol.clone(),
+        uri: data.uri.clone(),
+        seller_fee_basis_points: data.seller_fee_basis_points,
+        creators: parse_creators(&data.creators)?,
+        collection,
+    })
+}
+
+// Verified flags are encoded as given: an update has to repeat the flags
+// already stored on-chain, since unverifying another creator is rejected.
+fn parse_creators(creators: &[CreatorInput]) -> Result<Vec<(Pubkey, bool, u8)>> {
+    if creators.len() > MAX_CREATOR_LIMIT {
+        return Err(anyhow!("At most {} creators are allowed", MAX_CREATOR_LIMIT));
+    }
+    
+    let mut parsed: Vec<(Pubkey, bool, u8)> = Vec::new();
+    for creator in creators {
+        let address = Pubkey::from_str(&creator.address)
+            .map_err(|_| anyhow!("Invalid creator address: {}", creator.address))?;
+        if parsed.iter().any(|(existing, _, _)| *existing == address) {
+            return Err(anyhow!("Duplicate creator: {}", address));
+        }
+        parsed.push((address, creator.verified, creator.share));
+    }
+    
+    let total_share: u32 = parsed.iter().map(|(_, _, share)| *share as u32).sum();
+    if !parsed.is_empty() && total_share != 100 {
+        return Err(anyhow!("Creator shares must add up to 100, got {}", total_share));
+    }
+    
+    Ok(parsed)
+}
+
+fn write_data_v2(buffer: &mut Vec<u8>, data: &MetadataData) {
+    write_string(buffer, &data.name);
+    write_string(buffer, &data.symbol);
+    write_string(buffer, &data.uri);
+    buffer.extend_from_slice(&data.seller_fee_basis_points.to_le_bytes());
+    
+    if data.creators.is_empty() {
+        buffer.push(0);
+    } else {
+        buffer.push(1);
+        buffer.extend_from_slice(&(data.creators.len() as u32).to_le_bytes());
+        for (address, verified, share) in &data.creators {
+            buffer.extend_from_slice(address.as_ref());
+            write_bool(buffer, *verified);
+            buffer.push(*share);
+        }
+    }
+    
+    // A collection is always attached unverified; verifying it is a separate
+    // instruction signed by the collection authority.
+    match &data.collection {
+        Some(collection) => {
+            buffer.push(1);
+            write_bool(buffer, false);
+            buffer.extend_from_slice(collection.as_ref());
+        }
+        None => buffer.push(0),
+    }
+    
+    // uses
+    buffer.push(0);
+}
+
+fn write_string(buffer: &mut Vec<u8>, value: &str) {
+    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+fn write_bool(buffer: &mut Vec<u8>, value: bool) {
+    buffer.push(value as u8);
+}
+
+fn write_optional_bool(buffer: &mut Vec<u8>, value: Option<bool>) {
+    match value {
+        Some(value) => {
+            buffer.push(1);
+            write_bool(buffer, value);
+        }
+        None => buffer.push(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana_ops::instruction_from_response;
+
+    // Golden vectors produced by mpl-token-metadata 4.1.2 builders
+    // (`CreateMetadataAccountV3Builder`, `UpdateMetadataAccountV2Builder`) for
+    // the same inputs.
+    const GOLDEN_CREATE: &str = "210c000000476f6c64656e20546f6b656e03000000474c441f00000068747470733a2f2f6578616d706c652e636f6d2f676f6c64656e2e6a736f6ef40101020000000404040404040404040404040404040404040404040404040404040404040404013c0505050505050505050505050505050505050505050505050505050505050505002801000606060606060606060606060606060606060606060606060606060606060606000100";
+    const GOLDEN_CREATE_BARE: &str = "210400000042617265000000000000000000000000000000";
+    const GOLDEN_UPDATE: &str = "0f010c000000476f6c64656e20546f6b656e03000000474c441f00000068747470733a2f2f6578616d706c652e636f6d2f676f6c64656e2e6a736f6ef40101020000000404040404040404040404040404040404040404040404040404040404040404013c05050505050505050505050505050505050505050505050505050505050505050028010006060606060606060606060606060606060606060606060606060606060606060001070707070707070707070707070707070707070707070707070707070707070701010100";
+    const GOLDEN_UPDATE_PRIMARY_SALE: &str = "0f0000010100";
+    const GOLDEN_METADATA: &str = "A9e7KK1odkd3Zxc9YnF7Rsd7sCDiFQHgsCPVK3qZYRu2";
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn golden_data() -> MetadataDataInput {
+        MetadataDataInput {
+            name: "Golden Token".to_string(),
+            symbol: "GLD".to_string(),
+            uri: "https://example.com/golden.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: vec![
+                CreatorInput { address: key(4).to_string(), verified: true, share: 60 },
+                CreatorInput { address: key(5).to_string(), verified: false, share: 40 },
+            ],
+            collection: Some(key(6).to_string()),
+        }
+    }
+
+    fn decode(response: &MetadataInstructionResponse) -> Instruction {
+        assert_eq!(response.metadata, GOLDEN_METADATA);
+        let instruction = instruction_from_response(&response.instruction).unwrap();
+        assert_eq!(instruction.program_id, TOKEN_METADATA_PROGRAM_ID);
+        instruction
+    }
+
+    fn account_flags(instruction: &Instruction) -> Vec<(Pubkey, bool, bool)> {
+        instruction.accounts
+            .iter()
+            .map(|meta| (meta.pubkey, meta.is_writable, meta.is_signer))
+            .collect()
+    }
+
+    #[test]
+    fn metadata_address_matches_program_pda() {
+        assert_eq!(find_metadata_address(&key(1)).to_string(), GOLDEN_METADATA);
+    }
+
+    #[test]
+    fn create_metadata_matches_golden_bytes() {
+        let response = create_metadata_instruction(
+            &key(1).to_string(),
+            &key(2).to_string(),
+            Some(&key(3).to_string()),
+            Some(&key(4).to_string()),
+            &golden_data(),
+            true,
+        ).unwrap();
+        let instruction = decode(&response);
+        
+        assert_eq!(hex(&instruction.data), GOLDEN_CREATE);
+        assert_eq!(account_flags(&instruction), vec![
+            (find_metadata_address(&key(1)), true, false),
+            (key(1), false, false),
+            (key(2), false, true),
+            (key(3), true, true),
+            (key(4), false, true),
+            (system_program::ID, false, false),
+        ]);
+    }
+
+    #[test]
+    fn create_metadata_defaults_to_mint_authority() {
+        let data = MetadataDataInput {
+            name: "Bare".to_string(),
+            symbol: String::new(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators: Vec::new(),
+            collection: None,
+        };
+        let response = create_metadata_instruction(&key(1).to_string(), &key(2).to_string(), None, None, &data, false).unwrap();
+        let instruction = decode(&response);
+        
+        assert_eq!(hex(&instruction.data), GOLDEN_CREATE_BARE);
+        assert_eq!(account_flags(&instruction), vec![
+            (find_metadata_address(&key(1)), true, false),
+            (key(1), false, false),
+            (key(2), false, true),
+            (key(2), true, true),
+            (key(2), false, true),
+            (system_program::ID, false, false),
+        ]);
+    }
+
+    #[test]
+    fn update_metadata_matches_golden_bytes() {
+        let data = golden_data();
+        let response = update_metadata_instruction(
+            &key(1).to_string(),
+            &key(4).to_string(),
+            Some(&data),
+            Some(&key(7).to_string()),
+            Some(true),
+            Some(false),
+        ).unwrap();
+        let instruction = decode(&response);
+        
+        assert_eq!(hex(&instruction.data), GOLDEN_UPDATE);
+        assert_eq!(account_flags(&instruction), vec![
+            (find_metadata_address(&key(1)), true, false),
+            (key(4), false, true),
+        ]);
+    }
+
+    #[test]
+    fn update_metadata_encodes_absent_fields_as_none() {
+        let response = update_metadata_instruction(&key(1).to_string(), &key(4).to_string(), None, None, Some(true), None).unwrap();
+        let instruction = decode(&response);
+        
+        assert_eq!(hex(&instruction.data), GOLDEN_UPDATE_PRIMARY_SALE);
+    }
+
+    #[test]
+    fn creators_are_validated() {
+        let mut data = golden_data();
+        data.creators[1].share = 39;
+        assert!(create_metadata_instruction(&key(1).to_string(), &key(2).to_string(), None, Some(&key(4).to_string()), &data, true).is_err());
+        
+        let mut data = golden_data();
+        data.creators[1].address = key(4).to_string();
+        assert!(create_metadata_instruction(&key(1).to_string(), &key(2).to_string(), None, Some(&key(4).to_string()), &data, true).is_err());
+    }
+
+    #[test]
+    fn create_metadata_rejects_verifying_another_creator() {
+        let mut data = golden_data();
+        data.creators[1].verified = true;
+        let error = create_metadata_instruction(&key(1).to_string(), &key(2).to_string(), None, Some(&key(4).to_string()), &data, true)
+            .unwrap_err();
+        assert!(error.to_string().contains(&key(5).to_string()));
+    }
+
+    #[test]
+    fn update_metadata_keeps_third_party_creator_verified() {
+        let mut data = golden_data();
+        data.creators[0].verified = false;
+        data.creators[1].verified = true;
+        let response = update_metadata_instruction(&key(1).to_string(), &key(4).to_string(), Some(&data), None, None, None).unwrap();
+        let instruction = decode(&response);
+        
+        let creators = &instruction.data[62..];
+        assert_eq!(&creators[..5], &[1, 2, 0, 0, 0]);
+        assert_eq!(&creators[5..39], [key(4).as_ref(), &[0, 60]].concat().as_slice());
+        assert_eq!(&creators[39..73], [key(5).as_ref(), &[1, 40]].concat().as_slice());
+    }
+}
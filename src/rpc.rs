@@ -0,0 +1,1047 @@
+use solana_account_decoder::{UiAccountData, UiAccountEncoding};
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    nonce_utils::nonblocking as nonce_utils,
+    rpc_config::{
+        RpcSendTransactionConfig, RpcSimulateTransactionAccountsConfig,
+        RpcSimulateTransactionConfig,
+    },
+    rpc_request::{RpcRequest, TokenAccountsFilter},
+    rpc_response::Response as RpcResponse,
+};
+use solana_sdk::{
+    account::Account,
+    commitment_config::CommitmentConfig,
+    instruction::InstructionError,
+    message::VersionedMessage,
+    native_token::lamports_to_sol,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Signature,
+    transaction::TransactionError,
+};
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+use anyhow::{Result, anyhow};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use base64::{Engine as _, engine::general_purpose};
+use serde_json::{json, Value};
+use crate::types::{
+    AccountInfoResponse, BalanceResponse, BlockhashResponse, EstimateFeesResponse,
+    NonceAccountResponse, PrioritizationFeePercentiles, SendTransactionResponse,
+    SignatureStatusResponse, SimulateTransactionResponse, SimulationErrorResponse,
+    TokenAccountResponse, TokenAccountsResponse, TokenSupplyResponse,
+};
+use crate::transaction;
+
+pub type RpcHandle = Option<Arc<RpcClient>>;
+
+const DEFAULT_CONFIRMATION_TIMEOUT_SECS: u64 = 30;
+const MAX_CONFIRMATION_TIMEOUT_SECS: u64 = 120;
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn client_from_env() -> RpcHandle {
+    std::env::var("SOLANA_RPC_URL")
+        .ok()
+        .filter(|url| !url.is_empty())
+        .map(|url| Arc::new(RpcClient::new_with_commitment(url, CommitmentConfig::confirmed())))
+}
+
+pub async fn rent_exempt_minimum(rpc: &RpcHandle, space: usize) -> Result<u64> {
+    match rpc.as_deref() {
+        Some(client) => client.get_minimum_balance_for_rent_exemption(space)
+            .await
+            .map_err(|e| anyhow!("RPC error: {}", e)),
+        None => Ok(Rent::default().minimum_balance(space)),
+    }
+}
+
+fn require_client(rpc: &RpcHandle) -> Result<&RpcClient> {
+    rpc.as_deref()
+        .ok_or_else(|| anyhow!("RPC is not configured, set SOLANA_RPC_URL"))
+}
+
+fn parse_commitment(commitment: &str) -> Result<CommitmentConfig> {
+    match commitment {
+        "processed" => Ok(CommitmentConfig::processed()),
+        "confirmed" => Ok(CommitmentConfig::confirmed()),
+        "finalized" => Ok(CommitmentConfig::finalized()),
+        _ => Err(anyhow!("Commitment must be one of processed, confirmed or finalized")),
+    }
+}
+
+fn confirmation_timeout(timeout_seconds: Option<u64>) -> Duration {
+    let seconds = timeout_seconds
+        .unwrap_or(DEFAULT_CONFIRMATION_TIMEOUT_SECS)
+        .min(MAX_CONFIRMATION_TIMEOUT_SECS);
+    Duration::from_secs(seconds)
+}
+
+pub async fn send_transaction(
+    rpc: &RpcHandle,
+    transaction_b64: &str,
+    skip_preflight: bool,
+    max_retries: Option<usize>,
+    wait_for_commitment: Option<&str>,
+    timeout_seconds: Option<u64>,
+) -> Result<SendTransactionResponse> {
+    let client = require_client(rpc)?;
+    let commitment = wait_for_commitment.map(parse_commitment).transpose()?;
+    let transaction = transaction::decode_transaction(transaction_b64)?;
+    
+    if transaction.signatures.iter().any(|signature| *signature == Signature::default()) {
+        return Err(anyhow!("Transaction is missing required signatures"));
+    }
+    
+    let config = RpcSendTransactionConfig {
+        skip_preflight,
+        max_retries,
+        ..RpcSendTransactionConfig::default()
+    };
+    
+    let signature = client.send_transaction_with_config(&transaction, config)
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    let status = match commitment {
+        Some(commitment) => {
+            let timeout = confirmation_timeout(timeout_seconds);
+            Some(wait_for_status(client, &signature, commitment, timeout).await?)
+        }
+        None => None,
+    };
+    
+    Ok(SendTransactionResponse {
+        signature: signature.to_string(),
+        status,
+    })
+}
+
+pub async fn get_signature_status(
+    rpc: &RpcHandle,
+    signature: &str,
+    wait_for_commitment: Option<&str>,
+    timeout_seconds: Option<u64>,
+) -> Result<SignatureStatusResponse> {
+    let client = require_client(rpc)?;
+    let commitment = wait_for_commitment.map(parse_commitment).transpose()?;
+    
+    let signature = Signature::from_str(signature)
+        .map_err(|_| anyhow!("Invalid transaction signature"))?;
+    
+    match commitment {
+        Some(commitment) => {
+            let timeout = confirmation_timeout(timeout_seconds);
+            wait_for_status(client, &signature, commitment, timeout).await
+        }
+        None => {
+            let status = fetch_status(client, &signature).await?;
+            Ok(status_response(&signature, status.as_ref(), None))
+        }
+    }
+}
+
+pub async fn get_balance(rpc: &RpcHandle, pubkey: &str) -> Result<BalanceResponse> {
+    let client = require_client(rpc)?;
+    
+    let pubkey = Pubkey::from_str(pubkey)
+        .map_err(|_| anyhow!("Invalid account address"))?;
+    
+    let lamports = client.get_balance(&pubkey)
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    Ok(BalanceResponse {
+        pubkey: pubkey.to_string(),
+        lamports,
+        sol: lamports_to_sol(lamports),
+    })
+}
+
+pub async fn get_account_info(rpc: &RpcHandle, pubkey: &str) -> Result<AccountInfoResponse> {
+    let client = require_client(rpc)?;
+    
+    let pubkey = Pubkey::from_str(pubkey)
+        .map_err(|_| anyhow!("Invalid account address"))?;
+    
+    let account = client.get_account_with_commitment(&pubkey, client.commitment())
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?
+        .value
+        .ok_or_else(|| anyhow!("Account not found"))?;
+    
+    Ok(account_response(&pubkey, &account))
+}
+
+pub async fn get_nonce_account(rpc: &RpcHandle, nonce_account: &str) -> Result<NonceAccountResponse> {
+    let client = require_client(rpc)?;
+    
+    let nonce_account = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let account = nonce_utils::get_account_with_commitment(client, &nonce_account, client.commitment())
+        .await
+        .map_err(|e| anyhow!("Failed to load nonce account: {}", e))?;
+    
+    let data = nonce_utils::data_from_account(&account)
+        .map_err(|e| anyhow!("Invalid nonce account: {}", e))?;
+    
+    Ok(NonceAccountResponse {
+        address: nonce_account.to_string(),
+        authority: data.authority.to_string(),
+        nonce: data.blockhash().to_string(),
+        lamports_per_signature: data.fee_calculator.lamports_per_signature,
+    })
+}
+
+pub async fn get_token_accounts(rpc: &RpcHandle, owner: &str) -> Result<TokenAccountsResponse> {
+    let client = require_client(rpc)?;
+    
+    let owner = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let mut accounts = Vec::new();
+    for program_id in [spl_token::ID, spl_token_2022::ID] {
+        let keyed_accounts = client
+            .get_token_accounts_by_owner(&owner, TokenAccountsFilter::ProgramId(program_id))
+            .await
+            .map_err(|e| anyhow!("RPC error: {}", e))?;
+        
+        for keyed in keyed_accounts {
+            let UiAccountData::Json(parsed) = &keyed.account.data else {
+                return Err(anyhow!("RPC returned unparsed data for token account {}", keyed.pubkey));
+            };
+            accounts.push(parse_token_account(&keyed.pubkey, &keyed.account.owner, &parsed.parsed)?);
+        }
+    }
+    
+    Ok(TokenAccountsResponse {
+        owner: owner.to_string(),
+        accounts,
+    })
+}
+
+pub async fn get_token_supply(rpc: &RpcHandle, mint: &str) -> Result<TokenSupplyResponse> {
+    let client = require_client(rpc)?;
+    
+    let mint = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let supply = client.get_token_supply(&mint)
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    Ok(TokenSupplyResponse {
+        mint: mint.to_string(),
+        amount: supply.amount,
+        decimals: supply.decimals,
+        ui_amount: supply.ui_amount_string,
+    })
+}
+
+pub async fn simulate_transaction(
+    rpc: &RpcHandle,
+    transaction_b64: &str,
+    sig_verify: bool,
+    replace_recent_blockhash: Option<bool>,
+    accounts: &[String],
+) -> Result<SimulateTransactionResponse> {
+    let client = require_client(rpc)?;
+    
+    let replace_recent_blockhash = replace_recent_blockhash.unwrap_or(!sig_verify);
+    if sig_verify && replace_recent_blockhash {
+        return Err(anyhow!("sig_verify cannot be combined with replace_recent_blockhash"));
+    }
+    
+    let mut addresses = Vec::new();
+    for address in accounts {
+        addresses.push(Pubkey::from_str(address)
+            .map_err(|_| anyhow!("Invalid account address: {}", address))?);
+    }
+    
+    let transaction = transaction::decode_transaction(transaction_b64)?;
+    
+    let config = RpcSimulateTransactionConfig {
+        sig_verify,
+        replace_recent_blockhash,
+        accounts: if addresses.is_empty() {
+            None
+        } else {
+            Some(RpcSimulateTransactionAccountsConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                addresses: addresses.iter().map(|address| address.to_string()).collect(),
+            })
+        },
+        ..RpcSimulateTransactionConfig::default()
+    };
+    
+    let result = client.simulate_transaction_with_config(&transaction, config)
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?
+        .value;
+    
+    let accounts = result.accounts.map(|states| {
+        states
+            .iter()
+            .zip(&addresses)
+            .map(|(state, address)| {
+                state.as_ref()
+                    .and_then(|ui_account| ui_account.decode::<Account>())
+                    .map(|account| account_response(address, &account))
+            })
+            .collect()
+    });
+    
+    Ok(SimulateTransactionResponse {
+        err: result.err.as_ref().map(simulation_error),
+        logs: result.logs.unwrap_or_default(),
+        units_consumed: result.units_consumed,
+        accounts,
+    })
+}
+
+pub async fn get_latest_blockhash(rpc: &RpcHandle) -> Result<BlockhashResponse> {
+    let client = require_client(rpc)?;
+    
+    let (blockhash, last_valid_block_height) = client
+        .get_latest_blockhash_with_commitment(client.commitment())
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    Ok(BlockhashResponse {
+        blockhash: blockhash.to_string(),
+        last_valid_block_height,
+    })
+}
+
+// Lookup tables must be derived from a slot present in the SlotHashes sysvar,
+// so a finalized slot is used to avoid picking one from a dropped fork.
+pub async fn get_lookup_table_slot(rpc: &RpcHandle) -> Result<u64> {
+    let client = require_client(rpc)?;
+    
+    client.get_slot_with_commitment(CommitmentConfig::finalized())
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))
+}
+
+pub async fn estimate_fees(
+    rpc: &RpcHandle,
+    transaction_b64: Option<&str>,
+    message_b64: Option<&str>,
+) -> Result<EstimateFeesResponse> {
+    let client = require_client(rpc)?;
+    
+    let message = match (transaction_b64, message_b64) {
+        (Some(encoded), None) => transaction::decode_transaction(encoded)?.message,
+        (None, Some(encoded)) => transaction::decode_message(encoded)?,
+        _ => return Err(anyhow!("Provide exactly one of transaction or message")),
+    };
+    
+    let base_fee_lamports = fee_for_message(client, &message).await?;
+    
+    // Accounts loaded through lookup tables are unknown offline, so only the
+    // static writable keys are used to sample prioritization fees.
+    let writable_accounts: Vec<Pubkey> = message.static_account_keys()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index))
+        .map(|(_, key)| *key)
+        .collect();
+    
+    let mut fees: Vec<u64> = client.get_recent_prioritization_fees(&writable_accounts)
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?
+        .iter()
+        .map(|sample| sample.prioritization_fee)
+        .collect();
+    fees.sort_unstable();
+    
+    Ok(EstimateFeesResponse {
+        base_fee_lamports,
+        writable_accounts: writable_accounts.iter().map(|key| key.to_string()).collect(),
+        prioritization_fee_samples: fees.len(),
+        prioritization_fees_micro_lamports: fee_percentiles(&fees),
+    })
+}
+
+async fn fee_for_message(client: &RpcClient, message: &VersionedMessage) -> Result<u64> {
+    let encoded = general_purpose::STANDARD.encode(message.serialize());
+    let response: RpcResponse<Option<u64>> = client
+        .send(
+            RpcRequest::GetFeeForMessage,
+            json!([encoded, { "commitment": client.commitment().commitment }]),
+        )
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    response.value
+        .ok_or_else(|| anyhow!("RPC could not compute a fee, the blockhash may have expired"))
+}
+
+fn fee_percentiles(sorted_fees: &[u64]) -> Option<PrioritizationFeePercentiles> {
+    if sorted_fees.is_empty() {
+        return None;
+    }
+    
+    let percentile = |p: usize| {
+        let rank = (p * sorted_fees.len()).div_ceil(100).max(1);
+        sorted_fees[rank - 1]
+    };
+    
+    Some(PrioritizationFeePercentiles {
+        min: sorted_fees[0],
+        p25: percentile(25),
+        median: percentile(50),
+        p75: percentile(75),
+        p90: percentile(90),
+        max: sorted_fees[sorted_fees.len() - 1],
+    })
+}
+
+fn simulation_error(err: &TransactionError) -> SimulationErrorResponse {
+    match err {
+        TransactionError::InstructionError(index, instruction_error) => SimulationErrorResponse {
+            message: format!("Instruction {} failed: {}", index, instruction_error),
+            instruction_index: Some(*index),
+            instruction_error: Some(format!("{:?}", instruction_error)),
+            custom_code: match instruction_error {
+                InstructionError::Custom(code) => Some(*code),
+                _ => None,
+            },
+        },
+        _ => SimulationErrorResponse {
+            message: err.to_string(),
+            instruction_index: None,
+            instruction_error: None,
+            custom_code: None,
+        },
+    }
+}
+
+fn account_response(pubkey: &Pubkey, account: &Account) -> AccountInfoResponse {
+    AccountInfoResponse {
+        pubkey: pubkey.to_string(),
+        lamports: account.lamports,
+        owner: account.owner.to_string(),
+        executable: account.executable,
+        rent_epoch: account.rent_epoch,
+        space: account.data.len(),
+        data: general_purpose::STANDARD.encode(&account.data),
+    }
+}
+
+fn parse_token_account(address: &str, token_program: &str, parsed: &Value) -> Result<TokenAccountResponse> {
+    let info = &parsed["info"];
+    let token_amount = &info["tokenAmount"];
+    let field = |value: &Value, name: &str| -> Result<String> {
+        value[name]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| anyhow!("Token account {} is missing {}", address, name))
+    };
+    
+    Ok(TokenAccountResponse {
+        address: address.to_string(),
+        mint: field(info, "mint")?,
+        owner: field(info, "owner")?,
+        amount: field(token_amount, "amount")?,
+        decimals: token_amount["decimals"]
+            .as_u64()
+            .and_then(|decimals| u8::try_from(decimals).ok())
+            .ok_or_else(|| anyhow!("Token account {} is missing decimals", address))?,
+        ui_amount: field(token_amount, "uiAmountString")?,
+        state: field(info, "state")?,
+        token_program: token_program.to_string(),
+    })
+}
+
+async fn fetch_status(client: &RpcClient, signature: &Signature) -> Result<Option<TransactionStatus>> {
+    let response = client.get_signature_statuses_with_history(&[*signature])
+        .await
+        .map_err(|e| anyhow!("RPC error: {}", e))?;
+    
+    Ok(response.value.into_iter().next().flatten())
+}
+
+async fn wait_for_status(
+    client: &RpcClient,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+    timeout: Duration,
+) -> Result<SignatureStatusResponse> {
+    let deadline = Instant::now() + timeout;
+    
+    loop {
+        let status = fetch_status(client, signature).await?;
+    
+        if let Some(current) = &status {
+            // A failed transaction will never reach a higher commitment, so stop
+            // polling as soon as the error is visible.
+            if current.err.is_some() || current.satisfies_commitment(commitment) {
+                let reached = current.satisfies_commitment(commitment);
+                return Ok(status_response(signature, Some(current), Some(reached)));
+            }
+        }
+    
+        if Instant::now() >= deadline {
+            return Ok(status_response(signature, status.as_ref(), Some(false)));
+        }
+    
+        tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+    }
+}
+
+fn status_response(
+    signature: &Signature,
+    status: Option<&TransactionStatus>,
+    commitment_reached: Option<bool>,
+) -> SignatureStatusResponse {
+    match status {
+        Some(status) => SignatureStatusResponse {
+            signature: signature.to_string(),
+            found: true,
+            slot: Some(status.slot),
+            confirmations: status.confirmations,
+            confirmation_status: Some(confirmation_status_name(&status.confirmation_status()).to_string()),
+            err: status.err.as_ref().map(|err| err.to_string()),
+            commitment_reached,
+        },
+        None => SignatureStatusResponse {
+            signature: signature.to_string(),
+            found: false,
+            slot: None,
+            confirmations: None,
+            confirmation_status: None,
+            err: None,
+            commitment_reached,
+        },
+    }
+}
+
+fn confirmation_status_name(status: &TransactionConfirmationStatus) -> &'static str {
+    match status {
+        TransactionConfirmationStatus::Processed => "processed",
+        TransactionConfirmationStatus::Confirmed => "confirmed",
+        TransactionConfirmationStatus::Finalized => "finalized",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_client::{
+        client_error::{ClientError, ClientErrorKind, Result as ClientResult},
+        rpc_client::RpcClientConfig,
+        rpc_response::{RpcResponseContext, RpcSimulateTransactionResult},
+        rpc_sender::{RpcSender, RpcTransportStats},
+    };
+    use solana_sdk::{
+        hash::Hash,
+        message::Message,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    };
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+
+    // The mock sender answers "succeeds" with finalized statuses and
+    // "sig_not_found" with no status; `mocks` override a single response.
+    fn mock_rpc(url: &str, mocks: HashMap<RpcRequest, Value>) -> RpcHandle {
+        Some(Arc::new(RpcClient::new_mock_with_mocks(url.to_string(), mocks)))
+    }
+
+    type SentRequests = Arc<Mutex<Vec<(RpcRequest, Value)>>>;
+
+    // Unlike the mock sender, answers each request from a queue of responses
+    // and records the params it was called with.
+    struct ScriptedSender {
+        responses: Mutex<HashMap<RpcRequest, VecDeque<Value>>>,
+        sent: SentRequests,
+    }
+
+    #[async_trait::async_trait]
+    impl RpcSender for ScriptedSender {
+        async fn send(&self, request: RpcRequest, params: Value) -> ClientResult<Value> {
+            // The client checks the node version before some requests.
+            if request == RpcRequest::GetVersion {
+                return Ok(json!({ "solana-core": "1.18.26" }));
+            }
+            self.sent.lock().unwrap().push((request, params));
+            self.responses.lock().unwrap()
+                .get_mut(&request)
+                .and_then(|queue| queue.pop_front())
+                .ok_or_else(|| ClientError::from(ClientErrorKind::Custom(format!("unexpected {}", request))))
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            "scripted".to_string()
+        }
+    }
+
+    fn scripted_rpc(responses: Vec<(RpcRequest, Value)>) -> (RpcHandle, SentRequests) {
+        let mut queues: HashMap<RpcRequest, VecDeque<Value>> = HashMap::new();
+        for (request, response) in responses {
+            queues.entry(request).or_default().push_back(response);
+        }
+        let sent = SentRequests::default();
+        let sender = ScriptedSender { responses: Mutex::new(queues), sent: sent.clone() };
+        let client = RpcClient::new_sender(sender, RpcClientConfig::with_commitment(CommitmentConfig::confirmed()));
+        (Some(Arc::new(client)), sent)
+    }
+
+    fn with_context<T: serde::Serialize>(value: T) -> Value {
+        serde_json::to_value(RpcResponse {
+            context: RpcResponseContext { slot: 9, api_version: None },
+            value,
+        }).unwrap()
+    }
+
+    fn signed_transaction() -> (String, Signature) {
+        let payer = Keypair::new();
+        let instruction = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::default(),
+        );
+        let encoded = general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap());
+        (encoded, transaction.signatures[0])
+    }
+
+    fn status_mock(
+        confirmation_status: TransactionConfirmationStatus,
+        err: Option<TransactionError>,
+    ) -> HashMap<RpcRequest, Value> {
+        let status = TransactionStatus {
+            slot: 7,
+            confirmations: Some(1),
+            status: err.clone().map_or(Ok(()), Err),
+            err,
+            confirmation_status: Some(confirmation_status),
+        };
+        let response = RpcResponse {
+            context: RpcResponseContext { slot: 7, api_version: None },
+            value: vec![Some(status)],
+        };
+        HashMap::from([(RpcRequest::GetSignatureStatuses, serde_json::to_value(response).unwrap())])
+    }
+
+    #[tokio::test]
+    async fn send_returns_signature_without_waiting() {
+        let rpc = mock_rpc("succeeds", HashMap::new());
+        let (transaction, signature) = signed_transaction();
+        
+        let sent = send_transaction(&rpc, &transaction, true, None, None, None).await.unwrap();
+        assert_eq!(sent.signature, signature.to_string());
+        assert!(sent.status.is_none());
+    }
+
+    #[tokio::test]
+    async fn send_waits_for_finalized_commitment() {
+        let rpc = mock_rpc("succeeds", HashMap::new());
+        let (transaction, signature) = signed_transaction();
+        
+        let sent = send_transaction(&rpc, &transaction, true, Some(3), Some("finalized"), Some(5)).await.unwrap();
+        let status = sent.status.unwrap();
+        assert_eq!(status.signature, signature.to_string());
+        assert_eq!(status.confirmation_status.as_deref(), Some("finalized"));
+        assert_eq!(status.commitment_reached, Some(true));
+    }
+
+    #[tokio::test]
+    async fn send_rejects_unsigned_transaction() {
+        let rpc = mock_rpc("succeeds", HashMap::new());
+        let payer = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&payer, &Pubkey::new_unique(), 1);
+        let transaction = Transaction::new_with_payer(&[instruction], Some(&payer));
+        let encoded = general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap());
+        
+        assert!(send_transaction(&rpc, &encoded, true, None, None, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn status_lookup_reports_found_and_missing() {
+        let (_, signature) = signed_transaction();
+        
+        let found = get_signature_status(&mock_rpc("succeeds", HashMap::new()), &signature.to_string(), None, None)
+            .await
+            .unwrap();
+        assert!(found.found);
+        assert_eq!(found.slot, Some(1));
+        assert!(found.commitment_reached.is_none());
+        
+        let missing = get_signature_status(&mock_rpc("sig_not_found", HashMap::new()), &signature.to_string(), None, None)
+            .await
+            .unwrap();
+        assert!(!missing.found);
+        assert!(missing.confirmation_status.is_none());
+    }
+
+    #[tokio::test]
+    async fn wait_polls_until_confirmed() {
+        // The first poll only sees a processed status, the next one the mock's
+        // finalized default.
+        let rpc = mock_rpc("succeeds", status_mock(TransactionConfirmationStatus::Processed, None));
+        let (_, signature) = signed_transaction();
+        
+        let status = get_signature_status(&rpc, &signature.to_string(), Some("confirmed"), Some(5))
+            .await
+            .unwrap();
+        assert_eq!(status.confirmation_status.as_deref(), Some("finalized"));
+        assert_eq!(status.commitment_reached, Some(true));
+    }
+
+    #[tokio::test]
+    async fn wait_accepts_confirmed_status_for_confirmed_commitment() {
+        let rpc = mock_rpc("sig_not_found", status_mock(TransactionConfirmationStatus::Confirmed, None));
+        let (_, signature) = signed_transaction();
+        
+        let status = get_signature_status(&rpc, &signature.to_string(), Some("confirmed"), Some(5))
+            .await
+            .unwrap();
+        assert_eq!(status.slot, Some(7));
+        assert_eq!(status.confirmation_status.as_deref(), Some("confirmed"));
+        assert_eq!(status.commitment_reached, Some(true));
+    }
+
+    #[tokio::test]
+    async fn wait_stops_on_failed_transaction() {
+        let err = TransactionError::InstructionError(0, InstructionError::Custom(1));
+        let rpc = mock_rpc("sig_not_found", status_mock(TransactionConfirmationStatus::Processed, Some(err)));
+        let (_, signature) = signed_transaction();
+        
+        let status = get_signature_status(&rpc, &signature.to_string(), Some("finalized"), Some(5))
+            .await
+            .unwrap();
+        assert!(status.found);
+        assert!(status.err.is_some());
+        assert_eq!(status.commitment_reached, Some(false));
+    }
+
+    #[tokio::test]
+    async fn wait_times_out_when_commitment_is_not_reached() {
+        let rpc = mock_rpc("sig_not_found", HashMap::new());
+        let (_, signature) = signed_transaction();
+        
+        let started = Instant::now();
+        let status = get_signature_status(&rpc, &signature.to_string(), Some("confirmed"), Some(1))
+            .await
+            .unwrap();
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert!(!status.found);
+        assert_eq!(status.commitment_reached, Some(false));
+    }
+
+    #[tokio::test]
+    async fn requests_fail_without_rpc() {
+        let (transaction, signature) = signed_transaction();
+        
+        assert!(send_transaction(&None, &transaction, true, None, None, None).await.is_err());
+        assert!(get_signature_status(&None, &signature.to_string(), None, None).await.is_err());
+        assert!(get_signature_status(&mock_rpc("succeeds", HashMap::new()), "not-a-signature", None, None).await.is_err());
+    }
+
+    fn simulation_result(err: Option<TransactionError>) -> Value {
+        with_context(RpcSimulateTransactionResult {
+            err,
+            logs: Some(vec!["Program log: hello".to_string()]),
+            accounts: None,
+            units_consumed: Some(450),
+            return_data: None,
+            inner_instructions: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn simulate_replaces_blockhash_by_default_without_sig_verify() {
+        let (rpc, sent) = scripted_rpc(vec![(RpcRequest::SimulateTransaction, simulation_result(None))]);
+        let (transaction, _) = signed_transaction();
+        
+        let simulated = simulate_transaction(&rpc, &transaction, false, None, &[]).await.unwrap();
+        assert!(simulated.err.is_none());
+        assert_eq!(simulated.logs, vec!["Program log: hello"]);
+        assert_eq!(simulated.units_consumed, Some(450));
+        
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0].1[1]["sigVerify"], false);
+        assert_eq!(sent[0].1[1]["replaceRecentBlockhash"], true);
+    }
+
+    #[tokio::test]
+    async fn simulate_keeps_blockhash_by_default_with_sig_verify() {
+        let err = TransactionError::InstructionError(1, InstructionError::Custom(6001));
+        let (rpc, sent) = scripted_rpc(vec![(RpcRequest::SimulateTransaction, simulation_result(Some(err)))]);
+        let (transaction, _) = signed_transaction();
+        
+        let simulated = simulate_transaction(&rpc, &transaction, true, None, &[]).await.unwrap();
+        let err = simulated.err.unwrap();
+        assert_eq!(err.instruction_index, Some(1));
+        assert_eq!(err.custom_code, Some(6001));
+        
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0].1[1]["sigVerify"], true);
+        assert_eq!(sent[0].1[1]["replaceRecentBlockhash"], false);
+    }
+
+    #[tokio::test]
+    async fn simulate_rejects_sig_verify_with_blockhash_replacement() {
+        let (rpc, sent) = scripted_rpc(Vec::new());
+        let (transaction, _) = signed_transaction();
+        
+        let error = simulate_transaction(&rpc, &transaction, true, Some(true), &[]).await.unwrap_err();
+        assert!(error.to_string().contains("sig_verify cannot be combined with replace_recent_blockhash"));
+        assert!(sent.lock().unwrap().is_empty());
+        
+        let rpc = mock_rpc("succeeds", HashMap::new());
+        assert!(simulate_transaction(&rpc, &transaction, false, Some(true), &[]).await.is_ok());
+        assert!(simulate_transaction(&rpc, &transaction, true, Some(false), &[]).await.is_ok());
+    }
+
+    fn token_account(address: &Pubkey, mint: &Pubkey, owner: &Pubkey, token_program: &Pubkey) -> Value {
+        json!({
+            "pubkey": address.to_string(),
+            "account": {
+                "lamports": 2_039_280,
+                "data": {
+                    "program": "spl-token",
+                    "parsed": {
+                        "type": "account",
+                        "info": {
+                            "mint": mint.to_string(),
+                            "owner": owner.to_string(),
+                            "state": "initialized",
+                            "isNative": false,
+                            "tokenAmount": {
+                                "amount": "1500000",
+                                "decimals": 6,
+                                "uiAmount": 1.5,
+                                "uiAmountString": "1.5",
+                            },
+                        },
+                    },
+                    "space": 165,
+                },
+                "owner": token_program.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": 165,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn balance_reports_lamports_and_sol() {
+        let (rpc, sent) = scripted_rpc(vec![(RpcRequest::GetBalance, with_context(2_500_000_000u64))]);
+        let pubkey = Pubkey::new_unique();
+        
+        let balance = get_balance(&rpc, &pubkey.to_string()).await.unwrap();
+        assert_eq!(balance.pubkey, pubkey.to_string());
+        assert_eq!(balance.lamports, 2_500_000_000);
+        assert_eq!(balance.sol, 2.5);
+        assert_eq!(sent.lock().unwrap()[0].1[0], pubkey.to_string());
+        
+        assert!(get_balance(&rpc, "not-a-pubkey").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn account_info_encodes_data_and_reports_missing_accounts() {
+        let pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let account = json!({
+            "lamports": 1_000,
+            "data": [general_purpose::STANDARD.encode([1, 2, 3]), "base64"],
+            "owner": owner.to_string(),
+            "executable": false,
+            "rentEpoch": 5,
+            "space": 3,
+        });
+        let (rpc, _) = scripted_rpc(vec![
+            (RpcRequest::GetAccountInfo, with_context(account)),
+            (RpcRequest::GetAccountInfo, with_context(Value::Null)),
+        ]);
+        
+        let info = get_account_info(&rpc, &pubkey.to_string()).await.unwrap();
+        assert_eq!(info.pubkey, pubkey.to_string());
+        assert_eq!(info.lamports, 1_000);
+        assert_eq!(info.owner, owner.to_string());
+        assert_eq!(info.rent_epoch, 5);
+        assert_eq!(info.space, 3);
+        assert_eq!(info.data, "AQID");
+        
+        let error = get_account_info(&rpc, &pubkey.to_string()).await.unwrap_err();
+        assert_eq!(error.to_string(), "Account not found");
+    }
+
+    #[tokio::test]
+    async fn token_accounts_are_collected_from_both_token_programs() {
+        let owner = Pubkey::new_unique();
+        let (legacy_account, legacy_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (token_2022_account, token_2022_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (rpc, sent) = scripted_rpc(vec![
+            (RpcRequest::GetTokenAccountsByOwner, with_context(vec![token_account(&legacy_account, &legacy_mint, &owner, &spl_token::ID)])),
+            (RpcRequest::GetTokenAccountsByOwner, with_context(vec![token_account(&token_2022_account, &token_2022_mint, &owner, &spl_token_2022::ID)])),
+        ]);
+        
+        let accounts = get_token_accounts(&rpc, &owner.to_string()).await.unwrap();
+        assert_eq!(accounts.owner, owner.to_string());
+        assert_eq!(accounts.accounts.len(), 2);
+        
+        let legacy = &accounts.accounts[0];
+        assert_eq!(legacy.address, legacy_account.to_string());
+        assert_eq!(legacy.mint, legacy_mint.to_string());
+        assert_eq!(legacy.owner, owner.to_string());
+        assert_eq!(legacy.amount, "1500000");
+        assert_eq!(legacy.decimals, 6);
+        assert_eq!(legacy.ui_amount, "1.5");
+        assert_eq!(legacy.state, "initialized");
+        assert_eq!(legacy.token_program, spl_token::ID.to_string());
+        assert_eq!(accounts.accounts[1].token_program, spl_token_2022::ID.to_string());
+        
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0].1[1]["programId"], spl_token::ID.to_string());
+        assert_eq!(sent[1].1[1]["programId"], spl_token_2022::ID.to_string());
+        assert_eq!(sent[0].1[2]["encoding"], "jsonParsed");
+    }
+
+    #[tokio::test]
+    async fn token_supply_keeps_the_ui_amount_string() {
+        let supply = json!({
+            "amount": "1000000000000000000",
+            "decimals": 9,
+            "uiAmount": 1e9,
+            "uiAmountString": "1000000000",
+        });
+        let (rpc, _) = scripted_rpc(vec![(RpcRequest::GetTokenSupply, with_context(supply))]);
+        let mint = Pubkey::new_unique();
+        
+        let supply = get_token_supply(&rpc, &mint.to_string()).await.unwrap();
+        assert_eq!(supply.mint, mint.to_string());
+        assert_eq!(supply.amount, "1000000000000000000");
+        assert_eq!(supply.decimals, 9);
+        assert_eq!(supply.ui_amount, "1000000000");
+    }
+
+    #[tokio::test]
+    async fn account_queries_fail_without_rpc() {
+        let pubkey = Pubkey::new_unique().to_string();
+        
+        assert!(get_balance(&None, &pubkey).await.is_err());
+        assert!(get_account_info(&None, &pubkey).await.is_err());
+        assert!(get_token_accounts(&None, &pubkey).await.is_err());
+        assert!(get_token_supply(&None, &pubkey).await.is_err());
+    }
+
+    fn prioritization_fees(fees: &[u64]) -> Value {
+        Value::Array(fees.iter().enumerate()
+            .map(|(slot, fee)| json!({ "slot": slot, "prioritizationFee": fee }))
+            .collect())
+    }
+
+    #[test]
+    fn fee_percentiles_use_nearest_rank() {
+        assert!(fee_percentiles(&[]).is_none());
+        
+        let single = fee_percentiles(&[7]).unwrap();
+        assert_eq!((single.min, single.p25, single.median, single.p90, single.max), (7, 7, 7, 7, 7));
+        
+        let fees: Vec<u64> = (1..=10).collect();
+        let percentiles = fee_percentiles(&fees).unwrap();
+        assert_eq!(percentiles.min, 1);
+        assert_eq!(percentiles.p25, 3);
+        assert_eq!(percentiles.median, 5);
+        assert_eq!(percentiles.p75, 8);
+        assert_eq!(percentiles.p90, 9);
+        assert_eq!(percentiles.max, 10);
+        
+        let percentiles = fee_percentiles(&[100, 200, 300, 400]).unwrap();
+        assert_eq!((percentiles.p25, percentiles.median, percentiles.p75, percentiles.p90), (100, 200, 300, 400));
+    }
+
+    #[tokio::test]
+    async fn estimate_fees_queries_base_fee_and_samples_writable_accounts() {
+        let payer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let message = VersionedMessage::Legacy(Message::new(
+            &[system_instruction::transfer(&payer, &recipient, 1)],
+            Some(&payer),
+        ));
+        let encoded = general_purpose::STANDARD.encode(message.serialize());
+        let (rpc, sent) = scripted_rpc(vec![
+            (RpcRequest::GetFeeForMessage, with_context(Some(5_000u64))),
+            (RpcRequest::GetRecentPrioritizationFees, prioritization_fees(&[40, 0, 10, 30, 20])),
+        ]);
+        
+        let estimate = estimate_fees(&rpc, None, Some(&encoded)).await.unwrap();
+        assert_eq!(estimate.base_fee_lamports, 5_000);
+        assert_eq!(estimate.writable_accounts, vec![payer.to_string(), recipient.to_string()]);
+        assert_eq!(estimate.prioritization_fee_samples, 5);
+        let percentiles = estimate.prioritization_fees_micro_lamports.unwrap();
+        assert_eq!((percentiles.min, percentiles.median, percentiles.max), (0, 20, 40));
+        
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0].0, RpcRequest::GetFeeForMessage);
+        assert_eq!(sent[0].1, json!([encoded, { "commitment": "confirmed" }]));
+        assert_eq!(sent[1].1, json!([[payer.to_string(), recipient.to_string()]]));
+    }
+
+    #[tokio::test]
+    async fn estimate_fees_accepts_a_transaction_without_samples() {
+        let (transaction, _) = signed_transaction();
+        let (rpc, _) = scripted_rpc(vec![
+            (RpcRequest::GetFeeForMessage, with_context(Some(5_000u64))),
+            (RpcRequest::GetRecentPrioritizationFees, prioritization_fees(&[])),
+        ]);
+        
+        let estimate = estimate_fees(&rpc, Some(&transaction), None).await.unwrap();
+        assert_eq!(estimate.prioritization_fee_samples, 0);
+        assert!(estimate.prioritization_fees_micro_lamports.is_none());
+    }
+
+    #[tokio::test]
+    async fn estimate_fees_reports_expired_blockhash() {
+        let (transaction, _) = signed_transaction();
+        let (rpc, _) = scripted_rpc(vec![(RpcRequest::GetFeeForMessage, with_context(None::<u64>))]);
+        
+        let error = estimate_fees(&rpc, Some(&transaction), None).await.unwrap_err();
+        assert!(error.to_string().contains("blockhash may have expired"));
+    }
+
+    #[tokio::test]
+    async fn estimate_fees_requires_exactly_one_input() {
+        let (transaction, _) = signed_transaction();
+        let (rpc, sent) = scripted_rpc(Vec::new());
+        
+        assert!(estimate_fees(&rpc, None, None).await.is_err());
+        assert!(estimate_fees(&rpc, Some(&transaction), Some(&transaction)).await.is_err());
+        assert!(sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn latest_blockhash_reports_last_valid_height() {
+        let blockhash = Hash::new_unique();
+        let (rpc, _) = scripted_rpc(vec![(
+            RpcRequest::GetLatestBlockhash,
+            with_context(json!({ "blockhash": blockhash.to_string(), "lastValidBlockHeight": 321 })),
+        )]);
+        
+        let latest = get_latest_blockhash(&rpc).await.unwrap();
+        assert_eq!(latest.blockhash, blockhash.to_string());
+        assert_eq!(latest.last_valid_block_height, 321);
+    }
+
+    #[tokio::test]
+    async fn lookup_table_slot_uses_finalized_commitment() {
+        let (rpc, sent) = scripted_rpc(vec![(RpcRequest::GetSlot, json!(12_345))]);
+        
+        assert_eq!(get_lookup_table_slot(&rpc).await.unwrap(), 12_345);
+        assert_eq!(sent.lock().unwrap()[0].1, json!([{ "commitment": "finalized" }]));
+        assert!(get_lookup_table_slot(&None).await.is_err());
+    }
+}
@@ -1,63 +1,472 @@
 use solana_sdk::{
-    instruction::Instruction,
+    address_lookup_table::{instruction as lookup_table_instruction, state::LOOKUP_TABLE_MAX_ADDRESSES},
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta as SdkAccountMeta, Instruction},
+    nonce::State as NonceState,
+    program_pack::Pack,
     pubkey::Pubkey,
-    system_instruction,
-    system_program,
+    stake::{
+        instruction as stake_instruction,
+        state::{Authorized, Lockup, StakeAuthorize, StakeStateV2},
+    },
+    system_instruction::{self, MAX_PERMITTED_DATA_LENGTH},
 };
-use spl_token::{
-    instruction as spl_instruction,
-    ID as TOKEN_PROGRAM_ID,
+use spl_token_2022::{
+    extension::{
+        default_account_state::instruction::initialize_default_account_state,
+        interest_bearing_mint::instruction as interest_bearing_instruction,
+        metadata_pointer::instruction as metadata_pointer_instruction,
+        transfer_fee::instruction as transfer_fee_instruction,
+        ExtensionType,
+    },
+    instruction::{self as spl_instruction, AuthorityType},
+    state::{AccountState, Mint, Multisig},
+};
+use spl_token_metadata_interface::{
+    instruction as token_metadata_instruction,
+    state::{Field, TokenMetadata},
 };
 use spl_associated_token_account::{
-    get_associated_token_address,
-    instruction::create_associated_token_account,
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
 };
 use anyhow::{Result, anyhow};
 use std::str::FromStr;
 use base64::{Engine as _, engine::general_purpose};
-use crate::types::{AccountMeta, InstructionResponse};
+use crate::types::{
+    AccountMeta, AccountCreationResponse, InstructionResponse, LookupTableInstructionResponse, MintExtension,
+    SeededInstructionResponse, StakeLockup,
+};
+
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+pub fn instruction_to_response(instruction: &Instruction) -> InstructionResponse {
+    let mut accounts = Vec::new();
+    for acc in &instruction.accounts {
+        accounts.push(AccountMeta {
+            pubkey: acc.pubkey.to_string(),
+            is_signer: acc.is_signer,
+            is_writable: acc.is_writable,
+        });
+    }
+    
+    InstructionResponse {
+        program_id: instruction.program_id.to_string(),
+        accounts,
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    }
+}
+
+pub fn instruction_from_response(spec: &InstructionResponse) -> Result<Instruction> {
+    let program_id = Pubkey::from_str(&spec.program_id)
+        .map_err(|_| anyhow!("Invalid program id"))?;
+    
+    let mut accounts = Vec::new();
+    for acc in &spec.accounts {
+        let pubkey = Pubkey::from_str(&acc.pubkey)
+            .map_err(|_| anyhow!("Invalid account address: {}", acc.pubkey))?;
+        accounts.push(SdkAccountMeta {
+            pubkey,
+            is_signer: acc.is_signer,
+            is_writable: acc.is_writable,
+        });
+    }
+    
+    let data = general_purpose::STANDARD.decode(&spec.instruction_data)
+        .map_err(|_| anyhow!("Invalid base64 instruction data"))?;
+    
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data,
+    })
+}
 
 pub fn create_mint_instruction(
     mint_authority: &str,
     mint: &str,
     decimals: u8,
+    freeze_authority: Option<&str>,
+    token_program: Option<&str>,
 ) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
     let mint_authority_pubkey = Pubkey::from_str(mint_authority)
         .map_err(|_| anyhow!("Invalid mint authority address"))?;
     
     let mint_pubkey = Pubkey::from_str(mint)
         .map_err(|_| anyhow!("Invalid mint address"))?;
     
+    let freeze_authority_pubkey = freeze_authority
+        .map(|authority| Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid freeze authority address")))
+        .transpose()?;
+    
     let instruction = spl_instruction::initialize_mint(
-        &TOKEN_PROGRAM_ID,
+        &token_program_id,
         &mint_pubkey,
         &mint_authority_pubkey,
-        Some(&mint_authority_pubkey),
+        freeze_authority_pubkey.as_ref(),
         decimals,
     )?;
     
-    let mut accounts = Vec::new();
-    for acc in &instruction.accounts {
-        accounts.push(AccountMeta {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        });
+    Ok(instruction_to_response(&instruction))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint_account_instructions(
+    payer: &str,
+    mint_authority: &str,
+    mint: &str,
+    decimals: u8,
+    freeze_authority: Option<&str>,
+    extensions: &[MintExtension],
+    lamports: u64,
+    token_program: Option<&str>,
+) -> Result<AccountCreationResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let mint_authority_pubkey = Pubkey::from_str(mint_authority)
+        .map_err(|_| anyhow!("Invalid mint authority address"))?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let freeze_authority_pubkey = freeze_authority
+        .map(|authority| Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid freeze authority address")))
+        .transpose()?;
+    
+    let space = mint_account_size(token_program, extensions)?;
+    
+    let create_account = system_instruction::create_account(
+        &payer_pubkey,
+        &mint_pubkey,
+        lamports,
+        space as u64,
+        &token_program_id,
+    );
+    
+    let mut instructions = vec![instruction_to_response(&create_account)];
+    
+    for extension in extensions {
+        let instruction = mint_extension_instruction(
+            &token_program_id,
+            &mint_pubkey,
+            extension,
+            freeze_authority_pubkey.is_some(),
+        )?;
+        instructions.push(instruction_to_response(&instruction));
     }
     
-    Ok(InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let initialize_mint = spl_instruction::initialize_mint2(
+        &token_program_id,
+        &mint_pubkey,
+        &mint_authority_pubkey,
+        freeze_authority_pubkey.as_ref(),
+        decimals,
+    )?;
+    instructions.push(instruction_to_response(&initialize_mint));
+    
+    for instruction in token_metadata_instructions(&token_program_id, &mint_pubkey, &mint_authority_pubkey, extensions)? {
+        instructions.push(instruction_to_response(&instruction));
+    }
+    
+    Ok(AccountCreationResponse {
+        address: None,
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space,
+    })
+}
+
+pub fn create_multisig_instructions(
+    payer: &str,
+    multisig: &str,
+    signers: &[String],
+    m: u8,
+    lamports: u64,
+    token_program: Option<&str>,
+) -> Result<AccountCreationResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let multisig_pubkey = Pubkey::from_str(multisig)
+        .map_err(|_| anyhow!("Invalid multisig address"))?;
+    
+    let signer_pubkeys = parse_multisig_signers(signers)?;
+    
+    if signer_pubkeys.is_empty() {
+        return Err(anyhow!("At least one multisig signer is required"));
+    }
+    
+    if m == 0 || m as usize > signer_pubkeys.len() {
+        return Err(anyhow!("m must be between 1 and the number of signers ({})", signer_pubkeys.len()));
+    }
+    
+    for (index, signer) in signer_pubkeys.iter().enumerate() {
+        if signer_pubkeys[..index].contains(signer) {
+            return Err(anyhow!("Duplicate multisig signer: {}", signer));
+        }
+    }
+    
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+    let space = Multisig::LEN;
+    
+    let create_account = system_instruction::create_account(
+        &payer_pubkey,
+        &multisig_pubkey,
+        lamports,
+        space as u64,
+        &token_program_id,
+    );
+    
+    let initialize_multisig = spl_instruction::initialize_multisig(
+        &token_program_id,
+        &multisig_pubkey,
+        &signer_refs,
+        m,
+    )?;
+    
+    let instructions = vec![
+        instruction_to_response(&create_account),
+        instruction_to_response(&initialize_multisig),
+    ];
+    
+    Ok(AccountCreationResponse {
+        address: None,
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space,
     })
 }
 
+pub fn parse_token_program(token_program: Option<&str>) -> Result<Pubkey> {
+    match token_program.unwrap_or("spl-token") {
+        "spl-token" => Ok(spl_token::ID),
+        "token-2022" => Ok(spl_token_2022::ID),
+        other => Err(anyhow!("Token program must be spl-token or token-2022, got {}", other)),
+    }
+}
+
+pub fn mint_account_size(token_program: Option<&str>, extensions: &[MintExtension]) -> Result<usize> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    if token_program_id != spl_token_2022::ID {
+        if !extensions.is_empty() {
+            return Err(anyhow!("Mint extensions require the token-2022 program"));
+        }
+        return Ok(Mint::LEN);
+    }
+    
+    let mut extension_types: Vec<ExtensionType> = Vec::new();
+    for extension in extensions {
+        let extension_type = mint_extension_type(extension);
+        if extension_types.contains(&extension_type) {
+            return Err(anyhow!("Duplicate mint extension: {:?}", extension_type));
+        }
+        extension_types.push(extension_type);
+    }
+    
+    ExtensionType::try_calculate_account_len::<Mint>(&extension_types)
+        .map_err(|e| anyhow!("Failed to size mint account: {}", e))
+}
+
+// Token metadata is stored in a variable-length extension that the program
+// reallocates into, so it is funded up front but not part of the allocation.
+pub fn mint_metadata_size(extensions: &[MintExtension]) -> Result<usize> {
+    let Some(fields) = extensions.iter().find_map(|extension| match extension {
+        MintExtension::MetadataPointer { metadata, .. } => metadata.as_ref(),
+        _ => None,
+    }) else {
+        return Ok(0);
+    };
+    
+    let metadata = TokenMetadata {
+        name: fields.name.clone(),
+        symbol: fields.symbol.clone(),
+        uri: fields.uri.clone(),
+        additional_metadata: fields.additional_metadata.clone(),
+        ..Default::default()
+    };
+    
+    metadata.tlv_size_of()
+        .map_err(|e| anyhow!("Failed to size token metadata: {}", e))
+}
+
+fn mint_extension_type(extension: &MintExtension) -> ExtensionType {
+    match extension {
+        MintExtension::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
+        MintExtension::InterestBearing { .. } => ExtensionType::InterestBearingConfig,
+        MintExtension::MetadataPointer { .. } => ExtensionType::MetadataPointer,
+        MintExtension::NonTransferable => ExtensionType::NonTransferable,
+        MintExtension::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
+        MintExtension::DefaultAccountState { .. } => ExtensionType::DefaultAccountState,
+        MintExtension::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
+    }
+}
+
+fn mint_extension_instruction(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    extension: &MintExtension,
+    has_freeze_authority: bool,
+) -> Result<Instruction> {
+    let instruction = match extension {
+        MintExtension::TransferFeeConfig {
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        } => {
+            if *transfer_fee_basis_points > 10_000 {
+                return Err(anyhow!("Transfer fee basis points must be at most 10000"));
+            }
+            let config_authority = parse_optional_pubkey(
+                transfer_fee_config_authority.as_deref(),
+                "transfer fee config authority",
+            )?;
+            let withdraw_authority = parse_optional_pubkey(
+                withdraw_withheld_authority.as_deref(),
+                "withdraw withheld authority",
+            )?;
+            transfer_fee_instruction::initialize_transfer_fee_config(
+                token_program_id,
+                mint,
+                config_authority.as_ref(),
+                withdraw_authority.as_ref(),
+                *transfer_fee_basis_points,
+                *maximum_fee,
+            )?
+        }
+        MintExtension::InterestBearing { rate_authority, rate } => {
+            let rate_authority = parse_optional_pubkey(rate_authority.as_deref(), "rate authority")?;
+            interest_bearing_instruction::initialize(token_program_id, mint, rate_authority, *rate)?
+        }
+        MintExtension::MetadataPointer { authority, metadata_address, metadata } => {
+            let authority = parse_optional_pubkey(authority.as_deref(), "metadata pointer authority")?;
+            let metadata_address = match parse_optional_pubkey(metadata_address.as_deref(), "metadata address")? {
+                Some(address) => address,
+                None => *mint,
+            };
+            if metadata.is_some() && metadata_address != *mint {
+                return Err(anyhow!("Embedded metadata requires the metadata pointer to reference the mint"));
+            }
+            metadata_pointer_instruction::initialize(token_program_id, mint, authority, Some(metadata_address))?
+        }
+        MintExtension::NonTransferable => {
+            spl_instruction::initialize_non_transferable_mint(token_program_id, mint)?
+        }
+        MintExtension::PermanentDelegate { delegate } => {
+            let delegate = Pubkey::from_str(delegate)
+                .map_err(|_| anyhow!("Invalid permanent delegate address"))?;
+            spl_instruction::initialize_permanent_delegate(token_program_id, mint, &delegate)?
+        }
+        MintExtension::DefaultAccountState { state } => {
+            let state = match state.as_str() {
+                "initialized" => AccountState::Initialized,
+                "frozen" => AccountState::Frozen,
+                _ => return Err(anyhow!("Default account state must be initialized or frozen")),
+            };
+            if !has_freeze_authority {
+                return Err(anyhow!("Default account state requires a freeze authority"));
+            }
+            initialize_default_account_state(token_program_id, mint, &state)?
+        }
+        MintExtension::MintCloseAuthority { close_authority } => {
+            let close_authority = parse_optional_pubkey(close_authority.as_deref(), "close authority")?;
+            spl_instruction::initialize_mint_close_authority(token_program_id, mint, close_authority.as_ref())?
+        }
+    };
+    
+    Ok(instruction)
+}
+
+fn token_metadata_instructions(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    extensions: &[MintExtension],
+) -> Result<Vec<Instruction>> {
+    let Some(fields) = extensions.iter().find_map(|extension| match extension {
+        MintExtension::MetadataPointer { metadata, .. } => metadata.as_ref(),
+        _ => None,
+    }) else {
+        return Ok(Vec::new());
+    };
+    
+    let update_authority = parse_optional_pubkey(fields.update_authority.as_deref(), "metadata update authority")?
+        .unwrap_or(*mint_authority);
+    
+    let mut instructions = vec![token_metadata_instruction::initialize(
+        token_program_id,
+        mint,
+        &update_authority,
+        mint,
+        mint_authority,
+        fields.name.clone(),
+        fields.symbol.clone(),
+        fields.uri.clone(),
+    )];
+    
+    for (key, value) in &fields.additional_metadata {
+        instructions.push(token_metadata_instruction::update_field(
+            token_program_id,
+            mint,
+            &update_authority,
+            Field::Key(key.clone()),
+            value.clone(),
+        ));
+    }
+    
+    Ok(instructions)
+}
+
+fn parse_optional_pubkey(value: Option<&str>, name: &str) -> Result<Option<Pubkey>> {
+    value
+        .map(|value| Pubkey::from_str(value)
+            .map_err(|_| anyhow!("Invalid {} address", name)))
+        .transpose()
+}
+
+fn native_mint_for(token_program_id: &Pubkey) -> Pubkey {
+    if *token_program_id == spl_token_2022::ID {
+        spl_token_2022::native_mint::ID
+    } else {
+        spl_token::native_mint::ID
+    }
+}
+
+pub fn required_signers(instructions: &[InstructionResponse]) -> Vec<String> {
+    let mut signers: Vec<String> = Vec::new();
+    for instruction in instructions {
+        for account in &instruction.accounts {
+            if account.is_signer && !signers.contains(&account.pubkey) {
+                signers.push(account.pubkey.clone());
+            }
+        }
+    }
+    signers
+}
+
 pub fn create_mint_to_instruction(
     mint: &str,
     destination: &str,
     authority: &str,
     amount: u64,
+    decimals: Option<u8>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
 ) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
     let mint_pubkey = Pubkey::from_str(mint)
         .map_err(|_| anyhow!("Invalid mint address"))?;
     
@@ -67,29 +476,30 @@ pub fn create_mint_to_instruction(
     let authority_pubkey = Pubkey::from_str(authority)
         .map_err(|_| anyhow!("Invalid authority address"))?;
     
-    let instruction = spl_instruction::mint_to(
-        &TOKEN_PROGRAM_ID,
-        &mint_pubkey,
-        &destination_pubkey,
-        &authority_pubkey,
-        &[],
-        amount,
-    )?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
     
-    let mut accounts = Vec::new();
-    for acc in &instruction.accounts {
-        accounts.push(AccountMeta {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        });
-    }
+    let instruction = match decimals {
+        Some(decimals) => spl_instruction::mint_to_checked(
+            &token_program_id,
+            &mint_pubkey,
+            &destination_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        )?,
+        None => spl_instruction::mint_to(
+            &token_program_id,
+            &mint_pubkey,
+            &destination_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+            amount,
+        )?,
+    };
     
-    Ok(InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
-    })
+    Ok(instruction_to_response(&instruction))
 }
 
 pub fn create_sol_transfer_instruction(
@@ -109,65 +519,1527 @@ pub fn create_sol_transfer_instruction(
     
     let instruction = system_instruction::transfer(&from_pubkey, &to_pubkey, lamports);
     
-    let mut accounts = Vec::new();
-    for acc in &instruction.accounts {
-        accounts.push(AccountMeta {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        });
-    }
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_system_account_instruction(
+    payer: &str,
+    new_account: &str,
+    owner: &str,
+    space: u64,
+    lamports: u64,
+) -> Result<AccountCreationResponse> {
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
     
-    Ok(InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let new_account_pubkey = Pubkey::from_str(new_account)
+        .map_err(|_| anyhow!("Invalid new account address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
+    
+    check_account_space(space)?;
+    
+    let instruction = system_instruction::create_account(
+        &payer_pubkey,
+        &new_account_pubkey,
+        lamports,
+        space,
+        &owner_pubkey,
+    );
+    let instructions = vec![instruction_to_response(&instruction)];
+    
+    Ok(AccountCreationResponse {
+        address: None,
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space: space as usize,
     })
 }
 
-pub fn create_token_transfer_instruction(
-    mint: &str,
+pub fn create_account_with_seed_instruction(
+    payer: &str,
+    base: &str,
+    seed: &str,
     owner: &str,
-    destination: &str,
-    amount: u64,
-) -> Result<InstructionResponse> {
-    let mint_pubkey = Pubkey::from_str(mint)
-        .map_err(|_| anyhow!("Invalid mint address"))?;
+    space: u64,
+    lamports: u64,
+) -> Result<AccountCreationResponse> {
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let base_pubkey = Pubkey::from_str(base)
+        .map_err(|_| anyhow!("Invalid base address"))?;
     
     let owner_pubkey = Pubkey::from_str(owner)
-        .map_err(|_| anyhow!("Invalid owner address"))?;
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
     
-    let destination_pubkey = Pubkey::from_str(destination)
-        .map_err(|_| anyhow!("Invalid destination address"))?;
+    check_account_space(space)?;
+    let address = derive_seeded_address(&base_pubkey, seed, &owner_pubkey)?;
     
-    if amount == 0 {
+    let instruction = system_instruction::create_account_with_seed(
+        &payer_pubkey,
+        &address,
+        &base_pubkey,
+        seed,
+        lamports,
+        space,
+        &owner_pubkey,
+    );
+    let instructions = vec![instruction_to_response(&instruction)];
+    
+    Ok(AccountCreationResponse {
+        address: Some(address.to_string()),
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space: space as usize,
+    })
+}
+
+pub fn create_allocate_instruction(account: &str, space: u64) -> Result<InstructionResponse> {
+    let account_pubkey = Pubkey::from_str(account)
+        .map_err(|_| anyhow!("Invalid account address"))?;
+    
+    check_account_space(space)?;
+    
+    let instruction = system_instruction::allocate(&account_pubkey, space);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_assign_instruction(account: &str, owner: &str) -> Result<InstructionResponse> {
+    let account_pubkey = Pubkey::from_str(account)
+        .map_err(|_| anyhow!("Invalid account address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
+    
+    let instruction = system_instruction::assign(&account_pubkey, &owner_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_transfer_with_seed_instruction(
+    from_base: &str,
+    from_seed: &str,
+    from_owner: &str,
+    to: &str,
+    lamports: u64,
+) -> Result<SeededInstructionResponse> {
+    let from_base_pubkey = Pubkey::from_str(from_base)
+        .map_err(|_| anyhow!("Invalid base address"))?;
+    
+    let from_owner_pubkey = Pubkey::from_str(from_owner)
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
+    
+    let to_pubkey = Pubkey::from_str(to)
+        .map_err(|_| anyhow!("Invalid recipient address"))?;
+    
+    if lamports == 0 {
         return Err(anyhow!("Amount must be greater than 0"));
     }
     
-    let source_ata = get_associated_token_address(&owner_pubkey, &mint_pubkey);
-    let dest_ata = get_associated_token_address(&destination_pubkey, &mint_pubkey);
+    let from_pubkey = derive_seeded_address(&from_base_pubkey, from_seed, &from_owner_pubkey)?;
+    
+    let instruction = system_instruction::transfer_with_seed(
+        &from_pubkey,
+        &from_base_pubkey,
+        from_seed.to_string(),
+        &from_owner_pubkey,
+        &to_pubkey,
+        lamports,
+    );
+    
+    Ok(SeededInstructionResponse {
+        address: from_pubkey.to_string(),
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+pub fn create_allocate_with_seed_instruction(
+    base: &str,
+    seed: &str,
+    owner: &str,
+    space: u64,
+) -> Result<SeededInstructionResponse> {
+    let base_pubkey = Pubkey::from_str(base)
+        .map_err(|_| anyhow!("Invalid base address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
+    
+    check_account_space(space)?;
+    let address = derive_seeded_address(&base_pubkey, seed, &owner_pubkey)?;
     
-    let instruction = spl_instruction::transfer(
-        &TOKEN_PROGRAM_ID,
-        &source_ata,
-        &dest_ata,
+    let instruction = system_instruction::allocate_with_seed(
+        &address,
+        &base_pubkey,
+        seed,
+        space,
         &owner_pubkey,
-        &[],
-        amount,
-    )?;
+    );
     
-    let mut accounts = Vec::new();
-    for acc in &instruction.accounts {
-        accounts.push(AccountMeta {
-            pubkey: acc.pubkey.to_string(),
-            is_signer: acc.is_signer,
-            is_writable: acc.is_writable,
-        });
-    }
+    Ok(SeededInstructionResponse {
+        address: address.to_string(),
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+pub fn create_assign_with_seed_instruction(
+    base: &str,
+    seed: &str,
+    owner: &str,
+) -> Result<SeededInstructionResponse> {
+    let base_pubkey = Pubkey::from_str(base)
+        .map_err(|_| anyhow!("Invalid base address"))?;
     
-    Ok(InstructionResponse {
-        program_id: instruction.program_id.to_string(),
-        accounts,
-        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner program id"))?;
+    
+    let address = derive_seeded_address(&base_pubkey, seed, &owner_pubkey)?;
+    
+    let instruction = system_instruction::assign_with_seed(
+        &address,
+        &base_pubkey,
+        seed,
+        &owner_pubkey,
+    );
+    
+    Ok(SeededInstructionResponse {
+        address: address.to_string(),
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+pub fn create_nonce_account_instructions(
+    payer: &str,
+    nonce_account: &str,
+    authority: Option<&str>,
+    lamports: u64,
+) -> Result<AccountCreationResponse> {
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let nonce_pubkey = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let authority_pubkey = match authority {
+        Some(authority) => Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid nonce authority address"))?,
+        None => payer_pubkey,
+    };
+    
+    let instructions: Vec<InstructionResponse> = system_instruction::create_nonce_account(
+        &payer_pubkey,
+        &nonce_pubkey,
+        &authority_pubkey,
+        lamports,
+    )
+    .iter()
+    .map(instruction_to_response)
+    .collect();
+    
+    Ok(AccountCreationResponse {
+        address: None,
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space: NonceState::size(),
     })
 }
+
+pub fn create_advance_nonce_instruction(
+    nonce_account: &str,
+    authority: &str,
+) -> Result<InstructionResponse> {
+    let nonce_pubkey = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid nonce authority address"))?;
+    
+    let instruction = system_instruction::advance_nonce_account(&nonce_pubkey, &authority_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_withdraw_nonce_instruction(
+    nonce_account: &str,
+    authority: &str,
+    to: &str,
+    lamports: u64,
+) -> Result<InstructionResponse> {
+    let nonce_pubkey = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid nonce authority address"))?;
+    
+    let to_pubkey = Pubkey::from_str(to)
+        .map_err(|_| anyhow!("Invalid recipient address"))?;
+    
+    if lamports == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let instruction = system_instruction::withdraw_nonce_account(
+        &nonce_pubkey,
+        &authority_pubkey,
+        &to_pubkey,
+        lamports,
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_authorize_nonce_instruction(
+    nonce_account: &str,
+    authority: &str,
+    new_authority: &str,
+) -> Result<InstructionResponse> {
+    let nonce_pubkey = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid nonce authority address"))?;
+    
+    let new_authority_pubkey = Pubkey::from_str(new_authority)
+        .map_err(|_| anyhow!("Invalid new nonce authority address"))?;
+    
+    let instruction = system_instruction::authorize_nonce_account(
+        &nonce_pubkey,
+        &authority_pubkey,
+        &new_authority_pubkey,
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_stake_account_instructions(
+    payer: &str,
+    stake_account: &str,
+    staker: Option<&str>,
+    withdrawer: Option<&str>,
+    lockup: Option<&StakeLockup>,
+    lamports: u64,
+    rent_exempt_reserve: u64,
+) -> Result<AccountCreationResponse> {
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let staker_pubkey = parse_optional_pubkey(staker, "staker")?.unwrap_or(payer_pubkey);
+    let withdrawer_pubkey = parse_optional_pubkey(withdrawer, "withdrawer")?.unwrap_or(payer_pubkey);
+    
+    let lockup = match lockup {
+        Some(lockup) => Lockup {
+            unix_timestamp: lockup.unix_timestamp,
+            epoch: lockup.epoch,
+            custodian: Pubkey::from_str(&lockup.custodian)
+                .map_err(|_| anyhow!("Invalid custodian address"))?,
+        },
+        None => Lockup::default(),
+    };
+    
+    if lamports < rent_exempt_reserve {
+        return Err(anyhow!("Stake account needs at least {} lamports to be rent-exempt", rent_exempt_reserve));
+    }
+    
+    let authorized = Authorized {
+        staker: staker_pubkey,
+        withdrawer: withdrawer_pubkey,
+    };
+    
+    let instructions: Vec<InstructionResponse> = stake_instruction::create_account(
+        &payer_pubkey,
+        &stake_pubkey,
+        &authorized,
+        &lockup,
+        lamports,
+    )
+    .iter()
+    .map(instruction_to_response)
+    .collect();
+    
+    Ok(AccountCreationResponse {
+        address: None,
+        signers: required_signers(&instructions),
+        instructions,
+        lamports,
+        space: StakeStateV2::size_of(),
+    })
+}
+
+pub fn create_delegate_stake_instruction(
+    stake_account: &str,
+    staker: &str,
+    vote_account: &str,
+) -> Result<InstructionResponse> {
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let staker_pubkey = Pubkey::from_str(staker)
+        .map_err(|_| anyhow!("Invalid staker address"))?;
+    
+    let vote_pubkey = Pubkey::from_str(vote_account)
+        .map_err(|_| anyhow!("Invalid vote account address"))?;
+    
+    let instruction = stake_instruction::delegate_stake(&stake_pubkey, &staker_pubkey, &vote_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_deactivate_stake_instruction(
+    stake_account: &str,
+    staker: &str,
+) -> Result<InstructionResponse> {
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let staker_pubkey = Pubkey::from_str(staker)
+        .map_err(|_| anyhow!("Invalid staker address"))?;
+    
+    let instruction = stake_instruction::deactivate_stake(&stake_pubkey, &staker_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_withdraw_stake_instruction(
+    stake_account: &str,
+    withdrawer: &str,
+    to: &str,
+    lamports: u64,
+    custodian: Option<&str>,
+) -> Result<InstructionResponse> {
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let withdrawer_pubkey = Pubkey::from_str(withdrawer)
+        .map_err(|_| anyhow!("Invalid withdrawer address"))?;
+    
+    let to_pubkey = Pubkey::from_str(to)
+        .map_err(|_| anyhow!("Invalid recipient address"))?;
+    
+    let custodian_pubkey = parse_optional_pubkey(custodian, "custodian")?;
+    
+    if lamports == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let instruction = stake_instruction::withdraw(
+        &stake_pubkey,
+        &withdrawer_pubkey,
+        &to_pubkey,
+        lamports,
+        custodian_pubkey.as_ref(),
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+// The split destination has to hold its own rent-exempt reserve before the
+// split, so a payer can be given to prefund it in the same transaction.
+pub fn create_split_stake_instructions(
+    stake_account: &str,
+    staker: &str,
+    split_stake_account: &str,
+    lamports: u64,
+    payer: Option<&str>,
+    rent_exempt_reserve: u64,
+) -> Result<Vec<InstructionResponse>> {
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let staker_pubkey = Pubkey::from_str(staker)
+        .map_err(|_| anyhow!("Invalid staker address"))?;
+    
+    let split_pubkey = Pubkey::from_str(split_stake_account)
+        .map_err(|_| anyhow!("Invalid split stake account address"))?;
+    
+    let payer_pubkey = parse_optional_pubkey(payer, "payer")?;
+    
+    if lamports == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    if stake_pubkey == split_pubkey {
+        return Err(anyhow!("Split stake account must differ from the source stake account"));
+    }
+    
+    let mut instructions = Vec::new();
+    if let Some(payer_pubkey) = payer_pubkey {
+        let prefund = system_instruction::transfer(&payer_pubkey, &split_pubkey, rent_exempt_reserve);
+        instructions.push(instruction_to_response(&prefund));
+    }
+    
+    for instruction in stake_instruction::split(&stake_pubkey, &staker_pubkey, lamports, &split_pubkey) {
+        instructions.push(instruction_to_response(&instruction));
+    }
+    
+    Ok(instructions)
+}
+
+pub fn create_merge_stake_instructions(
+    destination_stake_account: &str,
+    source_stake_account: &str,
+    staker: &str,
+) -> Result<Vec<InstructionResponse>> {
+    let destination_pubkey = Pubkey::from_str(destination_stake_account)
+        .map_err(|_| anyhow!("Invalid destination stake account address"))?;
+    
+    let source_pubkey = Pubkey::from_str(source_stake_account)
+        .map_err(|_| anyhow!("Invalid source stake account address"))?;
+    
+    let staker_pubkey = Pubkey::from_str(staker)
+        .map_err(|_| anyhow!("Invalid staker address"))?;
+    
+    if destination_pubkey == source_pubkey {
+        return Err(anyhow!("Cannot merge a stake account into itself"));
+    }
+    
+    Ok(stake_instruction::merge(&destination_pubkey, &source_pubkey, &staker_pubkey)
+        .iter()
+        .map(instruction_to_response)
+        .collect())
+}
+
+pub fn create_authorize_stake_instruction(
+    stake_account: &str,
+    authority: &str,
+    new_authority: &str,
+    stake_authorize: &str,
+    custodian: Option<&str>,
+) -> Result<InstructionResponse> {
+    let stake_pubkey = Pubkey::from_str(stake_account)
+        .map_err(|_| anyhow!("Invalid stake account address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let new_authority_pubkey = Pubkey::from_str(new_authority)
+        .map_err(|_| anyhow!("Invalid new authority address"))?;
+    
+    let custodian_pubkey = parse_optional_pubkey(custodian, "custodian")?;
+    
+    let stake_authorize = match stake_authorize {
+        "staker" => StakeAuthorize::Staker,
+        "withdrawer" => StakeAuthorize::Withdrawer,
+        _ => return Err(anyhow!("Stake authorize must be staker or withdrawer")),
+    };
+    
+    let instruction = stake_instruction::authorize(
+        &stake_pubkey,
+        &authority_pubkey,
+        &new_authority_pubkey,
+        stake_authorize,
+        custodian_pubkey.as_ref(),
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_lookup_table_instruction(
+    authority: &str,
+    payer: Option<&str>,
+    recent_slot: u64,
+) -> Result<LookupTableInstructionResponse> {
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let payer_pubkey = parse_optional_pubkey(payer, "payer")?.unwrap_or(authority_pubkey);
+    
+    let (instruction, lookup_table) = lookup_table_instruction::create_lookup_table(
+        authority_pubkey,
+        payer_pubkey,
+        recent_slot,
+    );
+    
+    Ok(LookupTableInstructionResponse {
+        lookup_table: lookup_table.to_string(),
+        recent_slot,
+        instruction: instruction_to_response(&instruction),
+    })
+}
+
+pub fn create_extend_lookup_table_instruction(
+    lookup_table: &str,
+    authority: &str,
+    payer: Option<&str>,
+    addresses: &[String],
+) -> Result<InstructionResponse> {
+    let lookup_table_pubkey = Pubkey::from_str(lookup_table)
+        .map_err(|_| anyhow!("Invalid lookup table address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let payer_pubkey = parse_optional_pubkey(payer, "payer")?.unwrap_or(authority_pubkey);
+    
+    if addresses.is_empty() {
+        return Err(anyhow!("At least one address is required"));
+    }
+    
+    if addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+        return Err(anyhow!("A lookup table holds at most {} addresses", LOOKUP_TABLE_MAX_ADDRESSES));
+    }
+    
+    let mut new_addresses: Vec<Pubkey> = Vec::new();
+    for address in addresses {
+        let pubkey = Pubkey::from_str(address)
+            .map_err(|_| anyhow!("Invalid address: {}", address))?;
+        if new_addresses.contains(&pubkey) {
+            return Err(anyhow!("Duplicate address: {}", pubkey));
+        }
+        new_addresses.push(pubkey);
+    }
+    
+    let instruction = lookup_table_instruction::extend_lookup_table(
+        lookup_table_pubkey,
+        authority_pubkey,
+        Some(payer_pubkey),
+        new_addresses,
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_freeze_lookup_table_instruction(
+    lookup_table: &str,
+    authority: &str,
+) -> Result<InstructionResponse> {
+    let lookup_table_pubkey = Pubkey::from_str(lookup_table)
+        .map_err(|_| anyhow!("Invalid lookup table address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let instruction = lookup_table_instruction::freeze_lookup_table(lookup_table_pubkey, authority_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_deactivate_lookup_table_instruction(
+    lookup_table: &str,
+    authority: &str,
+) -> Result<InstructionResponse> {
+    let lookup_table_pubkey = Pubkey::from_str(lookup_table)
+        .map_err(|_| anyhow!("Invalid lookup table address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let instruction = lookup_table_instruction::deactivate_lookup_table(lookup_table_pubkey, authority_pubkey);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_close_lookup_table_instruction(
+    lookup_table: &str,
+    authority: &str,
+    recipient: Option<&str>,
+) -> Result<InstructionResponse> {
+    let lookup_table_pubkey = Pubkey::from_str(lookup_table)
+        .map_err(|_| anyhow!("Invalid lookup table address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid authority address"))?;
+    
+    let recipient_pubkey = parse_optional_pubkey(recipient, "recipient")?.unwrap_or(authority_pubkey);
+    
+    let instruction = lookup_table_instruction::close_lookup_table(
+        lookup_table_pubkey,
+        authority_pubkey,
+        recipient_pubkey,
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+fn derive_seeded_address(base: &Pubkey, seed: &str, owner: &Pubkey) -> Result<Pubkey> {
+    Pubkey::create_with_seed(base, seed, owner)
+        .map_err(|e| anyhow!("Failed to derive seeded address: {}", e))
+}
+
+pub fn check_account_space(space: u64) -> Result<()> {
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(anyhow!("Space must be at most {} bytes", MAX_PERMITTED_DATA_LENGTH));
+    }
+    Ok(())
+}
+
+pub fn create_token_transfer_instruction(
+    mint: &str,
+    owner: &str,
+    destination: &str,
+    amount: u64,
+    decimals: Option<u8>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let destination_pubkey = Pubkey::from_str(destination)
+        .map_err(|_| anyhow!("Invalid destination address"))?;
+    
+    if amount == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let source_ata = get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &token_program_id);
+    let dest_ata = get_associated_token_address_with_program_id(&destination_pubkey, &mint_pubkey, &token_program_id);
+    
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = match decimals {
+        Some(decimals) => spl_instruction::transfer_checked(
+            &token_program_id,
+            &source_ata,
+            &mint_pubkey,
+            &dest_ata,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        )?,
+        #[allow(deprecated)]
+        None => spl_instruction::transfer(
+            &token_program_id,
+            &source_ata,
+            &dest_ata,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+        )?,
+    };
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn resolve_token_amount(
+    amount: u64,
+    ui_amount: Option<&str>,
+    decimals: Option<u8>,
+) -> Result<u64> {
+    let Some(ui_amount) = ui_amount else {
+        return Ok(amount);
+    };
+    
+    if amount != 0 {
+        return Err(anyhow!("Provide either amount or ui_amount, not both"));
+    }
+    
+    let decimals = decimals
+        .ok_or_else(|| anyhow!("decimals is required when using ui_amount"))?;
+    
+    ui_amount_to_base_units(ui_amount, decimals)
+}
+
+fn ui_amount_to_base_units(ui_amount: &str, decimals: u8) -> Result<u64> {
+    let (whole, fraction) = match ui_amount.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (ui_amount, ""),
+    };
+    
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(anyhow!("Invalid UI amount: {}", ui_amount));
+    }
+    
+    // Trailing zeros carry no precision, so "0.10" is still valid at 1 decimal.
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(anyhow!("UI amount {} has more precision than {} decimals", ui_amount, decimals));
+    }
+    
+    let whole = if whole.is_empty() { "0" } else { whole };
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    digits.parse::<u64>()
+        .map_err(|_| anyhow!("UI amount {} is too large", ui_amount))
+}
+
+pub fn create_burn_instruction(
+    mint: &str,
+    owner: &str,
+    account: Option<&str>,
+    amount: u64,
+    decimals: Option<u8>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let account_pubkey = token_account_or_ata(account, &owner_pubkey, &mint_pubkey, &token_program_id)?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    if amount == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let instruction = match decimals {
+        Some(decimals) => spl_instruction::burn_checked(
+            &token_program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        )?,
+        None => spl_instruction::burn(
+            &token_program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+        )?,
+    };
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_approve_instruction(
+    mint: &str,
+    owner: &str,
+    delegate: &str,
+    account: Option<&str>,
+    amount: u64,
+    decimals: Option<u8>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let delegate_pubkey = Pubkey::from_str(delegate)
+        .map_err(|_| anyhow!("Invalid delegate address"))?;
+    
+    let account_pubkey = token_account_or_ata(account, &owner_pubkey, &mint_pubkey, &token_program_id)?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    if amount == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let instruction = match decimals {
+        Some(decimals) => spl_instruction::approve_checked(
+            &token_program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &delegate_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+            decimals,
+        )?,
+        None => spl_instruction::approve(
+            &token_program_id,
+            &account_pubkey,
+            &delegate_pubkey,
+            &owner_pubkey,
+            &signer_refs,
+            amount,
+        )?,
+    };
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_revoke_instruction(
+    mint: &str,
+    owner: &str,
+    account: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let account_pubkey = token_account_or_ata(account, &owner_pubkey, &mint_pubkey, &token_program_id)?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = spl_instruction::revoke(
+        &token_program_id,
+        &account_pubkey,
+        &owner_pubkey,
+        &signer_refs,
+    )?;
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_close_account_instruction(
+    mint: &str,
+    owner: &str,
+    destination: &str,
+    account: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let destination_pubkey = Pubkey::from_str(destination)
+        .map_err(|_| anyhow!("Invalid destination address"))?;
+    
+    let account_pubkey = token_account_or_ata(account, &owner_pubkey, &mint_pubkey, &token_program_id)?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = spl_instruction::close_account(
+        &token_program_id,
+        &account_pubkey,
+        &destination_pubkey,
+        &owner_pubkey,
+        &signer_refs,
+    )?;
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_freeze_account_instruction(
+    mint: &str,
+    freeze_authority: &str,
+    owner: Option<&str>,
+    account: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    freeze_or_thaw_instruction(true, mint, freeze_authority, owner, account, multisig_signers, token_program)
+}
+
+pub fn create_thaw_account_instruction(
+    mint: &str,
+    freeze_authority: &str,
+    owner: Option<&str>,
+    account: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    freeze_or_thaw_instruction(false, mint, freeze_authority, owner, account, multisig_signers, token_program)
+}
+
+fn freeze_or_thaw_instruction(
+    freeze: bool,
+    mint: &str,
+    freeze_authority: &str,
+    owner: Option<&str>,
+    account: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(freeze_authority)
+        .map_err(|_| anyhow!("Invalid freeze authority address"))?;
+    
+    let account_pubkey = match (account, owner) {
+        (Some(account), _) => Pubkey::from_str(account)
+            .map_err(|_| anyhow!("Invalid token account address"))?,
+        (None, Some(owner)) => {
+            let owner_pubkey = Pubkey::from_str(owner)
+                .map_err(|_| anyhow!("Invalid owner address"))?;
+            get_associated_token_address_with_program_id(&owner_pubkey, &mint_pubkey, &token_program_id)
+        }
+        (None, None) => return Err(anyhow!("Either a token account or its owner is required")),
+    };
+    
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = if freeze {
+        spl_instruction::freeze_account(
+            &token_program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+        )?
+    } else {
+        spl_instruction::thaw_account(
+            &token_program_id,
+            &account_pubkey,
+            &mint_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+        )?
+    };
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_set_authority_instruction(
+    account: &str,
+    authority_type: &str,
+    current_authority: &str,
+    new_authority: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let account_pubkey = Pubkey::from_str(account)
+        .map_err(|_| anyhow!("Invalid account address"))?;
+    
+    let current_authority_pubkey = Pubkey::from_str(current_authority)
+        .map_err(|_| anyhow!("Invalid current authority address"))?;
+    
+    let new_authority_pubkey = new_authority
+        .map(|authority| Pubkey::from_str(authority)
+            .map_err(|_| anyhow!("Invalid new authority address")))
+        .transpose()?;
+    
+    let authority_type = match authority_type {
+        "MintTokens" => AuthorityType::MintTokens,
+        "FreezeAccount" => AuthorityType::FreezeAccount,
+        "AccountOwner" => AuthorityType::AccountOwner,
+        "CloseAccount" => AuthorityType::CloseAccount,
+        "TransferFeeConfig" => AuthorityType::TransferFeeConfig,
+        "WithheldWithdraw" => AuthorityType::WithheldWithdraw,
+        "CloseMint" => AuthorityType::CloseMint,
+        "InterestRate" => AuthorityType::InterestRate,
+        "PermanentDelegate" => AuthorityType::PermanentDelegate,
+        "MetadataPointer" => AuthorityType::MetadataPointer,
+        _ => return Err(anyhow!("Authority type must be one of MintTokens, FreezeAccount, AccountOwner, CloseAccount, TransferFeeConfig, WithheldWithdraw, CloseMint, InterestRate, PermanentDelegate or MetadataPointer")),
+    };
+    
+    let is_extension_authority = !matches!(
+        authority_type,
+        AuthorityType::MintTokens | AuthorityType::FreezeAccount | AuthorityType::AccountOwner | AuthorityType::CloseAccount
+    );
+    if is_extension_authority && token_program_id != spl_token_2022::ID {
+        return Err(anyhow!("{:?} authority requires the token-2022 program", authority_type));
+    }
+    
+    if authority_type == AuthorityType::AccountOwner && new_authority_pubkey.is_none() {
+        return Err(anyhow!("AccountOwner authority cannot be revoked"));
+    }
+    
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = spl_instruction::set_authority(
+        &token_program_id,
+        &account_pubkey,
+        new_authority_pubkey.as_ref(),
+        authority_type,
+        &current_authority_pubkey,
+        &signer_refs,
+    )?;
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_withdraw_withheld_fees_instruction(
+    mint: &str,
+    destination: &str,
+    authority: &str,
+    sources: &[String],
+    multisig_signers: &[String],
+) -> Result<InstructionResponse> {
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let destination_pubkey = Pubkey::from_str(destination)
+        .map_err(|_| anyhow!("Invalid destination token account address"))?;
+    
+    let authority_pubkey = Pubkey::from_str(authority)
+        .map_err(|_| anyhow!("Invalid withdraw withheld authority address"))?;
+    
+    let source_pubkeys = parse_fee_sources(sources)?;
+    let source_refs: Vec<&Pubkey> = source_pubkeys.iter().collect();
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    let instruction = if source_refs.is_empty() {
+        transfer_fee_instruction::withdraw_withheld_tokens_from_mint(
+            &spl_token_2022::ID,
+            &mint_pubkey,
+            &destination_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+        )?
+    } else {
+        transfer_fee_instruction::withdraw_withheld_tokens_from_accounts(
+            &spl_token_2022::ID,
+            &mint_pubkey,
+            &destination_pubkey,
+            &authority_pubkey,
+            &signer_refs,
+            &source_refs,
+        )?
+    };
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_harvest_withheld_fees_instruction(
+    mint: &str,
+    sources: &[String],
+) -> Result<InstructionResponse> {
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let source_pubkeys = parse_fee_sources(sources)?;
+    if source_pubkeys.is_empty() {
+        return Err(anyhow!("At least one source token account is required"));
+    }
+    let source_refs: Vec<&Pubkey> = source_pubkeys.iter().collect();
+    
+    let instruction = transfer_fee_instruction::harvest_withheld_tokens_to_mint(
+        &spl_token_2022::ID,
+        &mint_pubkey,
+        &source_refs,
+    )?;
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+fn parse_fee_sources(sources: &[String]) -> Result<Vec<Pubkey>> {
+    let mut source_pubkeys = Vec::new();
+    for source in sources {
+        source_pubkeys.push(Pubkey::from_str(source)
+            .map_err(|_| anyhow!("Invalid source token account address: {}", source))?);
+    }
+    
+    if source_pubkeys.len() > u8::MAX as usize {
+        return Err(anyhow!("At most {} source token accounts are allowed", u8::MAX));
+    }
+    
+    Ok(source_pubkeys)
+}
+
+fn token_account_or_ata(
+    account: Option<&str>,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Result<Pubkey> {
+    match account {
+        Some(account) => Pubkey::from_str(account)
+            .map_err(|_| anyhow!("Invalid token account address")),
+        None => Ok(get_associated_token_address_with_program_id(owner, mint, token_program_id)),
+    }
+}
+
+fn parse_multisig_signers(multisig_signers: &[String]) -> Result<Vec<Pubkey>> {
+    if multisig_signers.len() > spl_instruction::MAX_SIGNERS {
+        return Err(anyhow!("At most {} multisig signers are allowed", spl_instruction::MAX_SIGNERS));
+    }
+    
+    let mut signers = Vec::new();
+    for signer in multisig_signers {
+        signers.push(Pubkey::from_str(signer)
+            .map_err(|_| anyhow!("Invalid multisig signer address: {}", signer))?);
+    }
+    
+    Ok(signers)
+}
+
+pub fn create_associated_token_account_instruction(
+    payer: &str,
+    owner: &str,
+    mint: &str,
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let payer_pubkey = Pubkey::from_str(payer)
+        .map_err(|_| anyhow!("Invalid payer address"))?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let mint_pubkey = Pubkey::from_str(mint)
+        .map_err(|_| anyhow!("Invalid mint address"))?;
+    
+    let instruction = create_associated_token_account_idempotent(
+        &payer_pubkey,
+        &owner_pubkey,
+        &mint_pubkey,
+        &token_program_id,
+    );
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_wrap_sol_instructions(
+    owner: &str,
+    lamports: u64,
+    payer: Option<&str>,
+    token_program: Option<&str>,
+) -> Result<Vec<InstructionResponse>> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let payer_pubkey = match payer {
+        Some(payer) => Pubkey::from_str(payer)
+            .map_err(|_| anyhow!("Invalid payer address"))?,
+        None => owner_pubkey,
+    };
+    
+    if lamports == 0 {
+        return Err(anyhow!("Amount must be greater than 0"));
+    }
+    
+    let native_mint = native_mint_for(&token_program_id);
+    let wrapped_account = get_associated_token_address_with_program_id(&owner_pubkey, &native_mint, &token_program_id);
+    
+    let create_account = create_associated_token_account_idempotent(
+        &payer_pubkey,
+        &owner_pubkey,
+        &native_mint,
+        &token_program_id,
+    );
+    let deposit = system_instruction::transfer(&owner_pubkey, &wrapped_account, lamports);
+    let sync = spl_instruction::sync_native(&token_program_id, &wrapped_account)?;
+    
+    Ok(vec![
+        instruction_to_response(&create_account),
+        instruction_to_response(&deposit),
+        instruction_to_response(&sync),
+    ])
+}
+
+pub fn create_unwrap_sol_instruction(
+    owner: &str,
+    account: Option<&str>,
+    destination: Option<&str>,
+    multisig_signers: &[String],
+    token_program: Option<&str>,
+) -> Result<InstructionResponse> {
+    let token_program_id = parse_token_program(token_program)?;
+    
+    let owner_pubkey = Pubkey::from_str(owner)
+        .map_err(|_| anyhow!("Invalid owner address"))?;
+    
+    let native_mint = native_mint_for(&token_program_id);
+    let wrapped_account = token_account_or_ata(account, &owner_pubkey, &native_mint, &token_program_id)?;
+    let signers = parse_multisig_signers(multisig_signers)?;
+    let signer_refs: Vec<&Pubkey> = signers.iter().collect();
+    
+    // A multisig owner is a token-program account, so SOL sent there is stuck.
+    let destination_pubkey = match parse_optional_pubkey(destination, "destination")? {
+        Some(destination) => destination,
+        None if signers.is_empty() => owner_pubkey,
+        None => return Err(anyhow!("A destination is required when unwrapping with multisig signers")),
+    };
+    
+    let instruction = spl_instruction::close_account(
+        &token_program_id,
+        &wrapped_account,
+        &destination_pubkey,
+        &owner_pubkey,
+        &signer_refs,
+    )?;
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn create_compute_budget_instructions(
+    compute_unit_limit: Option<u32>,
+    compute_unit_price_micro_lamports: Option<u64>,
+) -> Result<Vec<InstructionResponse>> {
+    if compute_unit_limit.is_none() && compute_unit_price_micro_lamports.is_none() {
+        return Err(anyhow!("Provide a compute unit limit, a compute unit price, or both"));
+    }
+    
+    let mut instructions = Vec::new();
+    
+    if let Some(limit) = compute_unit_limit {
+        if limit == 0 || limit > MAX_COMPUTE_UNIT_LIMIT {
+            return Err(anyhow!("Compute unit limit must be between 1 and {}", MAX_COMPUTE_UNIT_LIMIT));
+        }
+        let instruction = ComputeBudgetInstruction::set_compute_unit_limit(limit);
+        instructions.push(instruction_to_response(&instruction));
+    }
+    
+    if let Some(price) = compute_unit_price_micro_lamports {
+        let instruction = ComputeBudgetInstruction::set_compute_unit_price(price);
+        instructions.push(instruction_to_response(&instruction));
+    }
+    
+    Ok(instructions)
+}
+
+pub fn create_memo_instruction(memo: &str, signers: &[String]) -> Result<InstructionResponse> {
+    if memo.is_empty() {
+        return Err(anyhow!("Memo cannot be empty"));
+    }
+    
+    let mut signer_pubkeys: Vec<Pubkey> = Vec::new();
+    for signer in signers {
+        let pubkey = Pubkey::from_str(signer)
+            .map_err(|_| anyhow!("Invalid signer address: {}", signer))?;
+        if signer_pubkeys.contains(&pubkey) {
+            return Err(anyhow!("Duplicate signer: {}", pubkey));
+        }
+        signer_pubkeys.push(pubkey);
+    }
+    
+    let signer_refs: Vec<&Pubkey> = signer_pubkeys.iter().collect();
+    let instruction = spl_memo::build_memo(memo.as_bytes(), &signer_refs);
+    
+    Ok(instruction_to_response(&instruction))
+}
+
+pub fn with_compute_budget(
+    instructions: Vec<InstructionResponse>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price_micro_lamports: Option<u64>,
+) -> Result<Vec<InstructionResponse>> {
+    if compute_unit_limit.is_none() && compute_unit_price_micro_lamports.is_none() {
+        return Ok(instructions);
+    }
+    
+    let mut combined = create_compute_budget_instructions(compute_unit_limit, compute_unit_price_micro_lamports)?;
+    combined.extend(instructions);
+    Ok(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::system_instruction::SystemInstruction;
+    use spl_token_metadata_interface::instruction::TokenMetadataInstruction;
+    use crate::types::TokenMetadataFields;
+
+    #[test]
+    fn ui_amount_converts_to_base_units() {
+        assert_eq!(ui_amount_to_base_units("12.5", 6).unwrap(), 12_500_000);
+        assert_eq!(ui_amount_to_base_units("12", 0).unwrap(), 12);
+        assert_eq!(ui_amount_to_base_units("0.000001", 6).unwrap(), 1);
+        assert_eq!(ui_amount_to_base_units("007.25", 2).unwrap(), 725);
+    }
+
+    #[test]
+    fn ui_amount_accepts_missing_whole_or_fraction() {
+        assert_eq!(ui_amount_to_base_units(".5", 1).unwrap(), 5);
+        assert_eq!(ui_amount_to_base_units("5.", 1).unwrap(), 50);
+        assert_eq!(ui_amount_to_base_units(".0", 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn ui_amount_ignores_trailing_zeros_for_precision() {
+        assert_eq!(ui_amount_to_base_units("0.10", 1).unwrap(), 1);
+        assert_eq!(ui_amount_to_base_units("3.000", 0).unwrap(), 3);
+    }
+
+    #[test]
+    fn ui_amount_rejects_excess_precision() {
+        assert!(ui_amount_to_base_units("0.15", 1).is_err());
+        assert!(ui_amount_to_base_units("1.0000001", 6).is_err());
+    }
+
+    #[test]
+    fn ui_amount_rejects_overflow() {
+        assert_eq!(ui_amount_to_base_units("18446744073709551615", 0).unwrap(), u64::MAX);
+        assert!(ui_amount_to_base_units("18446744073709551616", 0).is_err());
+        assert!(ui_amount_to_base_units("18446744073709.551616", 6).is_err());
+    }
+
+    #[test]
+    fn ui_amount_rejects_malformed_input() {
+        for input in ["", ".", "+1", "-1", "1e3", "1,5", " 1", "1.2.3", "abc"] {
+            assert!(ui_amount_to_base_units(input, 6).is_err(), "accepted {:?}", input);
+        }
+    }
+
+    #[test]
+    fn resolve_token_amount_requires_decimals_for_ui_amount() {
+        assert_eq!(resolve_token_amount(42, None, None).unwrap(), 42);
+        assert_eq!(resolve_token_amount(0, Some("1.5"), Some(2)).unwrap(), 150);
+        assert!(resolve_token_amount(0, Some("1.5"), None).is_err());
+        assert!(resolve_token_amount(7, Some("1.5"), Some(2)).is_err());
+    }
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    #[test]
+    fn unwrap_sol_defaults_destination_to_single_owner() {
+        let owner = key(1);
+        let response = create_unwrap_sol_instruction(&owner.to_string(), None, None, &[], None).unwrap();
+        let instruction = instruction_from_response(&response).unwrap();
+        
+        let account = get_associated_token_address_with_program_id(&owner, &spl_token::native_mint::ID, &spl_token::ID);
+        assert_eq!(instruction.accounts[0].pubkey, account);
+        assert_eq!(instruction.accounts[1].pubkey, owner);
+        assert_eq!(instruction.accounts[2].pubkey, owner);
+    }
+
+    #[test]
+    fn unwrap_sol_with_multisig_requires_destination() {
+        let multisig = key(1).to_string();
+        let signers = vec![key(2).to_string(), key(3).to_string()];
+        assert!(create_unwrap_sol_instruction(&multisig, None, None, &signers, None).is_err());
+        
+        let response = create_unwrap_sol_instruction(&multisig, None, Some(&key(4).to_string()), &signers, None).unwrap();
+        let instruction = instruction_from_response(&response).unwrap();
+        assert_eq!(instruction.accounts[1].pubkey, key(4));
+        assert_eq!(instruction.accounts[2].pubkey, key(1));
+        assert!(!instruction.accounts[2].is_signer);
+        assert!(instruction.accounts[3].is_signer && instruction.accounts[4].is_signer);
+    }
+
+    fn created_account(spec: &InstructionResponse) -> (u64, u64, Pubkey) {
+        let instruction = instruction_from_response(spec).unwrap();
+        assert_eq!(instruction.program_id, solana_sdk::system_program::ID);
+        match bincode::deserialize(&instruction.data).unwrap() {
+            SystemInstruction::CreateAccount { lamports, space, owner } => (lamports, space, owner),
+            other => panic!("expected CreateAccount, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn mint_creation_allocates_before_initializing() {
+        let (payer, authority, mint) = (key(1), key(2), key(3));
+        let created = create_mint_account_instructions(
+            &payer.to_string(),
+            &authority.to_string(),
+            &mint.to_string(),
+            6,
+            Some(&authority.to_string()),
+            &[],
+            1_461_600,
+            None,
+        ).unwrap();
+        
+        assert_eq!(created.space, Mint::LEN);
+        assert_eq!(created.lamports, 1_461_600);
+        assert_eq!(created.signers, vec![payer.to_string(), mint.to_string()]);
+        assert_eq!(created.instructions.len(), 2);
+        assert_eq!(created_account(&created.instructions[0]), (1_461_600, Mint::LEN as u64, spl_token::ID));
+        
+        let initialize = instruction_from_response(&created.instructions[1]).unwrap();
+        assert_eq!(initialize.program_id, spl_token::ID);
+        assert!(matches!(
+            spl_instruction::TokenInstruction::unpack(&initialize.data).unwrap(),
+            spl_instruction::TokenInstruction::InitializeMint2 { decimals: 6, .. },
+        ));
+    }
+
+    fn extensions_with_metadata() -> Vec<MintExtension> {
+        vec![
+            MintExtension::TransferFeeConfig {
+                transfer_fee_config_authority: None,
+                withdraw_withheld_authority: None,
+                transfer_fee_basis_points: 25,
+                maximum_fee: 1_000,
+            },
+            MintExtension::MintCloseAuthority { close_authority: Some(key(4).to_string()) },
+            MintExtension::MetadataPointer {
+                authority: None,
+                metadata_address: None,
+                metadata: Some(TokenMetadataFields {
+                    name: "Coin".to_string(),
+                    symbol: "CN".to_string(),
+                    uri: "https://example.com/coin".to_string(),
+                    update_authority: None,
+                    additional_metadata: vec![("k".to_string(), "v".to_string())],
+                }),
+            },
+        ]
+    }
+
+    #[test]
+    fn mint_size_covers_extensions_and_metadata_tlv() {
+        let extensions = extensions_with_metadata();
+        let account_len = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::MetadataPointer,
+        ]).unwrap();
+        assert_eq!(mint_account_size(Some("token-2022"), &extensions).unwrap(), account_len);
+        
+        // 8-byte discriminator and 4-byte length, update authority and mint,
+        // then borsh strings and the additional metadata vector.
+        let metadata_len = 8 + 4 + 32 + 32 + (4 + 4) + (4 + 2) + (4 + 24) + (4 + (4 + 1) + (4 + 1));
+        assert_eq!(mint_metadata_size(&extensions).unwrap(), metadata_len);
+        assert_eq!(mint_metadata_size(&extensions[..2]).unwrap(), 0);
+        
+        assert!(mint_account_size(None, &extensions).is_err());
+        let duplicated: Vec<MintExtension> = extensions_with_metadata().into_iter().chain(extensions_with_metadata()).collect();
+        assert!(mint_account_size(Some("token-2022"), &duplicated).is_err());
+    }
+
+    #[test]
+    fn mint_extensions_initialize_around_initialize_mint() {
+        let (payer, authority, mint) = (key(1), key(2), key(3));
+        let extensions = extensions_with_metadata();
+        let created = create_mint_account_instructions(
+            &payer.to_string(),
+            &authority.to_string(),
+            &mint.to_string(),
+            2,
+            None,
+            &extensions,
+            5_000_000,
+            Some("token-2022"),
+        ).unwrap();
+        let space = mint_account_size(Some("token-2022"), &extensions).unwrap();
+        
+        assert_eq!(created.space, space);
+        assert_eq!(created.instructions.len(), 7);
+        assert_eq!(created_account(&created.instructions[0]), (5_000_000, space as u64, spl_token_2022::ID));
+        
+        let instructions: Vec<Instruction> = created.instructions[1..]
+            .iter()
+            .map(|spec| instruction_from_response(spec).unwrap())
+            .collect();
+        assert!(instructions.iter().all(|instruction| instruction.program_id == spl_token_2022::ID));
+        assert!(instructions.iter().all(|instruction| instruction.accounts[0].pubkey == mint));
+        
+        let token_instruction = |index: usize| spl_instruction::TokenInstruction::unpack(&instructions[index].data).unwrap();
+        assert!(matches!(token_instruction(0), spl_instruction::TokenInstruction::TransferFeeExtension(_)));
+        assert!(matches!(token_instruction(1), spl_instruction::TokenInstruction::InitializeMintCloseAuthority { .. }));
+        assert!(matches!(token_instruction(2), spl_instruction::TokenInstruction::MetadataPointerExtension));
+        assert!(matches!(token_instruction(3), spl_instruction::TokenInstruction::InitializeMint2 { decimals: 2, .. }));
+        
+        let metadata_instruction = |index: usize| TokenMetadataInstruction::unpack(&instructions[index].data).unwrap();
+        assert!(matches!(metadata_instruction(4), TokenMetadataInstruction::Initialize(_)));
+        assert!(matches!(metadata_instruction(5), TokenMetadataInstruction::UpdateField(_)));
+    }
+}
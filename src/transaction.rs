@@ -0,0 +1,659 @@
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::{Transaction, VersionedTransaction},
+};
+use anyhow::{Result, anyhow};
+use std::str::FromStr;
+use base64::{Engine as _, engine::general_purpose};
+use crate::types::{
+    AddressTableLookupResponse, BuildTransactionResponse, BuildVersionedTransactionResponse,
+    DecodeTransactionResponse, InstructionResponse, LookupTableAccount, MessageHeaderResponse,
+    SignedTransactionResponse, TransactionSignatureInfo,
+};
+use crate::crypto;
+use crate::decoder;
+use crate::solana_ops;
+
+pub fn build_transaction(
+    instructions: &[InstructionResponse],
+    fee_payer: &str,
+    recent_blockhash: &str,
+) -> Result<BuildTransactionResponse> {
+    let fee_payer_pubkey = Pubkey::from_str(fee_payer)
+        .map_err(|_| anyhow!("Invalid fee payer address"))?;
+    
+    let blockhash = Hash::from_str(recent_blockhash)
+        .map_err(|_| anyhow!("Invalid recent blockhash"))?;
+    
+    let parsed = parse_instructions(instructions)?;
+    
+    let message = Message::new_with_blockhash(&parsed, Some(&fee_payer_pubkey), &blockhash);
+    let signers = required_signers(&message);
+    let transaction = Transaction::new_unsigned(message);
+    
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| anyhow!("Failed to serialize transaction: {}", e))?;
+    
+    Ok(BuildTransactionResponse {
+        transaction: general_purpose::STANDARD.encode(bytes),
+        signers,
+    })
+}
+
+pub fn build_nonce_transaction(
+    instructions: &[InstructionResponse],
+    fee_payer: &str,
+    nonce_account: &str,
+    nonce_authority: &str,
+    nonce: &str,
+) -> Result<BuildTransactionResponse> {
+    let fee_payer_pubkey = Pubkey::from_str(fee_payer)
+        .map_err(|_| anyhow!("Invalid fee payer address"))?;
+    
+    let nonce_account_pubkey = Pubkey::from_str(nonce_account)
+        .map_err(|_| anyhow!("Invalid nonce account address"))?;
+    
+    let nonce_authority_pubkey = Pubkey::from_str(nonce_authority)
+        .map_err(|_| anyhow!("Invalid nonce authority address"))?;
+    
+    let nonce_hash = Hash::from_str(nonce)
+        .map_err(|_| anyhow!("Invalid nonce value"))?;
+    
+    let parsed = parse_instructions(instructions)?;
+    
+    // `new_with_nonce` puts AdvanceNonceAccount first, as the runtime requires.
+    let mut message = Message::new_with_nonce(
+        parsed,
+        Some(&fee_payer_pubkey),
+        &nonce_account_pubkey,
+        &nonce_authority_pubkey,
+    );
+    message.recent_blockhash = nonce_hash;
+    
+    let signers = required_signers(&message);
+    let transaction = Transaction::new_unsigned(message);
+    
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| anyhow!("Failed to serialize transaction: {}", e))?;
+    
+    Ok(BuildTransactionResponse {
+        transaction: general_purpose::STANDARD.encode(bytes),
+        signers,
+    })
+}
+
+pub fn build_versioned_transaction(
+    instructions: &[InstructionResponse],
+    fee_payer: &str,
+    recent_blockhash: &str,
+    lookup_tables: &[LookupTableAccount],
+) -> Result<BuildVersionedTransactionResponse> {
+    let fee_payer_pubkey = Pubkey::from_str(fee_payer)
+        .map_err(|_| anyhow!("Invalid fee payer address"))?;
+    
+    let blockhash = Hash::from_str(recent_blockhash)
+        .map_err(|_| anyhow!("Invalid recent blockhash"))?;
+    
+    let parsed = parse_instructions(instructions)?;
+    
+    let mut tables = Vec::new();
+    for table in lookup_tables {
+        let key = Pubkey::from_str(&table.address)
+            .map_err(|_| anyhow!("Invalid lookup table address: {}", table.address))?;
+        
+        let mut addresses = Vec::new();
+        for address in &table.addresses {
+            addresses.push(Pubkey::from_str(address)
+                .map_err(|_| anyhow!("Invalid lookup table entry: {}", address))?);
+        }
+        
+        tables.push(AddressLookupTableAccount { key, addresses });
+    }
+    
+    let message = v0::Message::try_compile(&fee_payer_pubkey, &parsed, &tables, blockhash)
+        .map_err(|e| anyhow!("Failed to compile v0 message: {}", e))?;
+    
+    let num_signers = message.header.num_required_signatures as usize;
+    let signers = message.account_keys[..num_signers]
+        .iter()
+        .map(|key| key.to_string())
+        .collect();
+    
+    let address_table_lookups = message.address_table_lookups
+        .iter()
+        .map(|lookup| AddressTableLookupResponse {
+            account_key: lookup.account_key.to_string(),
+            writable_indexes: lookup.writable_indexes.clone(),
+            readonly_indexes: lookup.readonly_indexes.clone(),
+        })
+        .collect();
+    
+    let transaction = VersionedTransaction {
+        signatures: vec![Signature::default(); num_signers],
+        message: VersionedMessage::V0(message),
+    };
+    
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| anyhow!("Failed to serialize transaction: {}", e))?;
+    
+    Ok(BuildVersionedTransactionResponse {
+        size: bytes.len(),
+        transaction: general_purpose::STANDARD.encode(bytes),
+        signers,
+        address_table_lookups,
+    })
+}
+
+pub fn sign_transaction(transaction_b64: &str, secrets: &[String]) -> Result<SignedTransactionResponse> {
+    if secrets.is_empty() {
+        return Err(anyhow!("At least one secret key is required"));
+    }
+    
+    let mut transaction = decode_unsigned_transaction(transaction_b64)?;
+    let message_bytes = transaction.message.serialize();
+    
+    for secret in secrets {
+        let keypair = crypto::keypair_from_secret(secret)?;
+        let index = signer_index(&transaction, &keypair.pubkey())
+            .ok_or_else(|| anyhow!("{} is not a required signer", keypair.pubkey()))?;
+        transaction.signatures[index] = keypair.sign_message(&message_bytes);
+    }
+    
+    signed_transaction_response(&transaction)
+}
+
+pub fn merge_transactions(transactions: &[String]) -> Result<SignedTransactionResponse> {
+    let first = transactions.first()
+        .ok_or_else(|| anyhow!("At least one transaction is required"))?;
+    
+    let mut merged = decode_transaction(first)?;
+    let message_bytes = merged.message.serialize();
+    
+    for (position, encoded) in transactions.iter().enumerate() {
+        let candidate = decode_transaction(encoded)?;
+        
+        if candidate.message.serialize() != message_bytes {
+            return Err(anyhow!("Transaction {} does not share the same message", position));
+        }
+        
+        let signer_keys = &candidate.message.static_account_keys()[..candidate.signatures.len()];
+        for (index, signature) in candidate.signatures.iter().enumerate() {
+            if *signature == Signature::default() {
+                continue;
+            }
+            if !signature.verify(signer_keys[index].as_ref(), &message_bytes) {
+                return Err(anyhow!("Transaction {} has an invalid signature for {}", position, signer_keys[index]));
+            }
+            merged.signatures[index] = *signature;
+        }
+    }
+    
+    signed_transaction_response(&merged)
+}
+
+pub fn explain_transaction(encoded: &str, encoding: Option<&str>) -> Result<DecodeTransactionResponse> {
+    let transaction = match encoding {
+        Some("base64") => decode_transaction(encoded)?,
+        Some("base58") => decode_base58_transaction(encoded)?,
+        Some(other) => return Err(anyhow!("Unsupported encoding: {}", other)),
+        None => decode_transaction(encoded)
+            .or_else(|_| decode_base58_transaction(encoded))
+            .map_err(|_| anyhow!("Transaction is neither valid base64 nor base58"))?,
+    };
+    
+    let message = &transaction.message;
+    let header = message.header();
+    let message_bytes = message.serialize();
+    let static_keys = message.static_account_keys();
+    
+    let signatures = transaction.signatures
+        .iter()
+        .enumerate()
+        .map(|(index, signature)| {
+            let status = if *signature == Signature::default() {
+                "missing"
+            } else if signature.verify(static_keys[index].as_ref(), &message_bytes) {
+                "valid"
+            } else {
+                "invalid"
+            };
+            TransactionSignatureInfo {
+                signer: static_keys[index].to_string(),
+                signature: signature.to_string(),
+                status: status.to_string(),
+            }
+        })
+        .collect();
+    
+    let lookups = message.address_table_lookups().unwrap_or(&[]);
+    
+    // Accounts loaded through lookup tables cannot be resolved offline, so they
+    // are shown as `<table>:<index>` after the static keys, writable ones first.
+    let mut resolved_keys: Vec<String> = static_keys.iter().map(|key| key.to_string()).collect();
+    for lookup in lookups {
+        for index in &lookup.writable_indexes {
+            resolved_keys.push(format!("{}:{}", lookup.account_key, index));
+        }
+    }
+    for lookup in lookups {
+        for index in &lookup.readonly_indexes {
+            resolved_keys.push(format!("{}:{}", lookup.account_key, index));
+        }
+    }
+    
+    let mut instructions = Vec::new();
+    for compiled in message.instructions() {
+        let program_id = static_keys.get(compiled.program_id_index as usize)
+            .ok_or_else(|| anyhow!("Instruction program id index is out of range"))?;
+        
+        let mut accounts = Vec::new();
+        for index in &compiled.accounts {
+            accounts.push(resolved_keys.get(*index as usize)
+                .cloned()
+                .ok_or_else(|| anyhow!("Instruction account index is out of range"))?);
+        }
+        
+        instructions.push(decoder::decode_instruction(program_id, &accounts, &compiled.data));
+    }
+    
+    let version = match message {
+        VersionedMessage::Legacy(_) => "legacy".to_string(),
+        VersionedMessage::V0(_) => "0".to_string(),
+    };
+    
+    Ok(DecodeTransactionResponse {
+        version,
+        header: MessageHeaderResponse {
+            num_required_signatures: header.num_required_signatures,
+            num_readonly_signed_accounts: header.num_readonly_signed_accounts,
+            num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts,
+        },
+        account_keys: static_keys.iter().map(|key| key.to_string()).collect(),
+        recent_blockhash: message.recent_blockhash().to_string(),
+        signatures,
+        instructions,
+        address_table_lookups: lookups
+            .iter()
+            .map(|lookup| AddressTableLookupResponse {
+                account_key: lookup.account_key.to_string(),
+                writable_indexes: lookup.writable_indexes.clone(),
+                readonly_indexes: lookup.readonly_indexes.clone(),
+            })
+            .collect(),
+    })
+}
+
+pub fn decode_transaction(transaction_b64: &str) -> Result<VersionedTransaction> {
+    let bytes = general_purpose::STANDARD.decode(transaction_b64)
+        .map_err(|_| anyhow!("Invalid base64 transaction"))?;
+    
+    deserialize_transaction(&bytes, false)
+}
+
+// Unsigned transactions are often serialized without any signature slots, so
+// the signing path fills them in before the signatures are added.
+fn decode_unsigned_transaction(transaction_b64: &str) -> Result<VersionedTransaction> {
+    let bytes = general_purpose::STANDARD.decode(transaction_b64)
+        .map_err(|_| anyhow!("Invalid base64 transaction"))?;
+    
+    deserialize_transaction(&bytes, true)
+}
+
+pub fn decode_message(message_b64: &str) -> Result<VersionedMessage> {
+    let bytes = general_purpose::STANDARD.decode(message_b64)
+        .map_err(|_| anyhow!("Invalid base64 message"))?;
+    
+    bincode::deserialize(&bytes)
+        .map_err(|_| anyhow!("Invalid message encoding"))
+}
+
+fn decode_base58_transaction(transaction_b58: &str) -> Result<VersionedTransaction> {
+    let bytes = bs58::decode(transaction_b58)
+        .into_vec()
+        .map_err(|_| anyhow!("Invalid base58 transaction"))?;
+    
+    deserialize_transaction(&bytes, false)
+}
+
+fn deserialize_transaction(bytes: &[u8], pad_unsigned: bool) -> Result<VersionedTransaction> {
+    let mut transaction: VersionedTransaction = bincode::deserialize(bytes)
+        .map_err(|_| anyhow!("Invalid transaction encoding"))?;
+    
+    let num_signers = transaction.message.header().num_required_signatures as usize;
+    if transaction.message.static_account_keys().len() < num_signers {
+        return Err(anyhow!("Transaction header requires more signers than account keys"));
+    }
+    if pad_unsigned && transaction.signatures.is_empty() {
+        transaction.signatures = vec![Signature::default(); num_signers];
+    }
+    
+    if transaction.signatures.len() != num_signers {
+        return Err(anyhow!(
+            "Transaction has {} signatures but its header requires {}",
+            transaction.signatures.len(),
+            num_signers,
+        ));
+    }
+    
+    Ok(transaction)
+}
+
+fn signer_index(transaction: &VersionedTransaction, pubkey: &Pubkey) -> Option<usize> {
+    let num_signers = transaction.message.header().num_required_signatures as usize;
+    transaction.message.static_account_keys()[..num_signers]
+        .iter()
+        .position(|key| key == pubkey)
+}
+
+fn signed_transaction_response(transaction: &VersionedTransaction) -> Result<SignedTransactionResponse> {
+    let signer_keys = transaction.message.static_account_keys();
+    
+    let mut signed = Vec::new();
+    let mut missing_signers = Vec::new();
+    for (index, signature) in transaction.signatures.iter().enumerate() {
+        if *signature == Signature::default() {
+            missing_signers.push(signer_keys[index].to_string());
+        } else {
+            signed.push(signer_keys[index].to_string());
+        }
+    }
+    
+    let bytes = bincode::serialize(transaction)
+        .map_err(|e| anyhow!("Failed to serialize transaction: {}", e))?;
+    
+    Ok(SignedTransactionResponse {
+        transaction: general_purpose::STANDARD.encode(bytes),
+        complete: missing_signers.is_empty(),
+        signed,
+        missing_signers,
+    })
+}
+
+fn parse_instructions(instructions: &[InstructionResponse]) -> Result<Vec<Instruction>> {
+    if instructions.is_empty() {
+        return Err(anyhow!("At least one instruction is required"));
+    }
+    
+    let mut parsed = Vec::new();
+    for spec in instructions {
+        parsed.push(solana_ops::instruction_from_response(spec)?);
+    }
+    
+    Ok(parsed)
+}
+
+fn required_signers(message: &Message) -> Vec<String> {
+    let num_signers = message.header.num_required_signatures as usize;
+    message.account_keys[..num_signers]
+        .iter()
+        .map(|key| key.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{signature::Keypair, system_instruction};
+
+    fn transfer_transaction(signatures: Vec<Signature>, payer: &Pubkey) -> String {
+        let instruction = system_instruction::transfer(payer, &Pubkey::new_unique(), 1);
+        let message = Message::new_with_blockhash(&[instruction], Some(payer), &Hash::default());
+        let transaction = Transaction { signatures, message };
+        general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap())
+    }
+
+    #[test]
+    fn decode_rejects_signature_count_mismatch() {
+        let payer = Pubkey::new_unique();
+        let missing = transfer_transaction(vec![], &payer);
+        let extra = transfer_transaction(vec![Signature::default(); 2], &payer);
+        assert!(decode_transaction(&missing).is_err());
+        assert!(decode_transaction(&extra).is_err());
+        assert!(explain_transaction(&extra, Some("base64")).is_err());
+        assert!(decode_transaction(&transfer_transaction(vec![Signature::default()], &payer)).is_ok());
+    }
+
+    #[test]
+    fn sign_fills_in_missing_signature_slots() {
+        let payer = Keypair::new();
+        let unsigned = transfer_transaction(vec![], &payer.pubkey());
+        let secret = bs58::encode(payer.to_bytes()).into_string();
+        assert!(sign_transaction(&unsigned, std::slice::from_ref(&secret)).is_ok());
+        
+        let extra = transfer_transaction(vec![Signature::default(); 2], &payer.pubkey());
+        assert!(sign_transaction(&extra, &[secret]).is_err());
+    }
+
+    fn secret(keypair: &Keypair) -> String {
+        bs58::encode(keypair.to_bytes()).into_string()
+    }
+
+    fn two_signer_transaction(fee_payer: &Keypair, sender: &Keypair) -> String {
+        let instructions = vec![transfer_spec(&sender.pubkey(), &Pubkey::new_unique(), 1)];
+        build_transaction(&instructions, &fee_payer.pubkey().to_string(), &Hash::new_unique().to_string())
+            .unwrap()
+            .transaction
+    }
+
+    fn transfer_spec(from: &Pubkey, to: &Pubkey, lamports: u64) -> InstructionResponse {
+        solana_ops::instruction_to_response(&system_instruction::transfer(from, to, lamports))
+    }
+
+    #[test]
+    fn build_orders_signers_from_account_flags() {
+        let fee_payer = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let memo_signer = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let instructions = vec![
+            transfer_spec(&sender, &Pubkey::new_unique(), 5_000),
+            solana_ops::create_memo_instruction("payout", &[memo_signer.to_string()]).unwrap(),
+        ];
+        
+        let built = build_transaction(&instructions, &fee_payer.to_string(), &blockhash.to_string()).unwrap();
+        assert_eq!(built.signers, vec![fee_payer.to_string(), sender.to_string(), memo_signer.to_string()]);
+        
+        let transaction = decode_transaction(&built.transaction).unwrap();
+        assert!(matches!(transaction.message, VersionedMessage::Legacy(_)));
+        assert_eq!(transaction.signatures, vec![Signature::default(); 3]);
+        assert_eq!(*transaction.message.recent_blockhash(), blockhash);
+        assert_eq!(transaction.message.instructions().len(), 2);
+        
+        let header = transaction.message.header();
+        assert_eq!(header.num_readonly_signed_accounts, 1);
+    }
+
+    #[test]
+    fn build_rejects_invalid_input() {
+        let fee_payer = Pubkey::new_unique().to_string();
+        let blockhash = Hash::new_unique().to_string();
+        let instructions = vec![transfer_spec(&Pubkey::new_unique(), &Pubkey::new_unique(), 1)];
+        
+        assert!(build_transaction(&[], &fee_payer, &blockhash).is_err());
+        assert!(build_transaction(&instructions, "not-a-pubkey", &blockhash).is_err());
+        assert!(build_transaction(&instructions, &fee_payer, "not-a-hash").is_err());
+    }
+
+    #[test]
+    fn versioned_build_loads_accounts_through_lookup_tables() {
+        let fee_payer = Pubkey::new_unique();
+        let recipients: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        let instructions: Vec<InstructionResponse> = recipients
+            .iter()
+            .map(|recipient| transfer_spec(&fee_payer, recipient, 1_000))
+            .collect();
+        let table = LookupTableAccount {
+            address: Pubkey::new_unique().to_string(),
+            addresses: recipients.iter().map(|key| key.to_string()).collect(),
+        };
+        let blockhash = Hash::new_unique().to_string();
+        
+        let without_tables = build_versioned_transaction(&instructions, &fee_payer.to_string(), &blockhash, &[]).unwrap();
+        let built = build_versioned_transaction(&instructions, &fee_payer.to_string(), &blockhash, &[table]).unwrap();
+        
+        assert_eq!(built.signers, vec![fee_payer.to_string()]);
+        assert_eq!(built.size, general_purpose::STANDARD.decode(&built.transaction).unwrap().len());
+        assert!(built.size < without_tables.size);
+        assert_eq!(built.address_table_lookups.len(), 1);
+        assert_eq!(built.address_table_lookups[0].writable_indexes, (0..20).collect::<Vec<u8>>());
+        assert!(built.address_table_lookups[0].readonly_indexes.is_empty());
+        
+        let transaction = decode_transaction(&built.transaction).unwrap();
+        assert!(matches!(transaction.message, VersionedMessage::V0(_)));
+        assert_eq!(transaction.message.static_account_keys(), &[fee_payer, solana_sdk::system_program::ID]);
+        assert_eq!(transaction.signatures.len(), 1);
+    }
+
+    #[test]
+    fn versioned_build_rejects_invalid_lookup_tables() {
+        let fee_payer = Pubkey::new_unique().to_string();
+        let blockhash = Hash::new_unique().to_string();
+        let instructions = vec![transfer_spec(&Pubkey::new_unique(), &Pubkey::new_unique(), 1)];
+        let table = |address: &str, entry: &str| LookupTableAccount {
+            address: address.to_string(),
+            addresses: vec![entry.to_string()],
+        };
+        let valid = Pubkey::new_unique().to_string();
+        
+        assert!(build_versioned_transaction(&instructions, &fee_payer, &blockhash, &[table("bad", &valid)]).is_err());
+        assert!(build_versioned_transaction(&instructions, &fee_payer, &blockhash, &[table(&valid, "bad")]).is_err());
+    }
+
+    #[test]
+    fn sign_adds_signatures_at_signer_indices() {
+        let fee_payer = Keypair::new();
+        let sender = Keypair::new();
+        let unsigned = two_signer_transaction(&fee_payer, &sender);
+        
+        let partial = sign_transaction(&unsigned, &[secret(&sender)]).unwrap();
+        assert!(!partial.complete);
+        assert_eq!(partial.signed, vec![sender.pubkey().to_string()]);
+        assert_eq!(partial.missing_signers, vec![fee_payer.pubkey().to_string()]);
+        
+        let signed = sign_transaction(&partial.transaction, &[secret(&fee_payer)]).unwrap();
+        assert!(signed.complete);
+        assert_eq!(signed.signed, vec![fee_payer.pubkey().to_string(), sender.pubkey().to_string()]);
+        
+        let transaction = decode_transaction(&signed.transaction).unwrap();
+        assert_eq!(transaction.verify_with_results(), vec![true, true]);
+    }
+
+    #[test]
+    fn sign_pads_transactions_serialized_without_signatures() {
+        let payer = Keypair::new();
+        let unsigned = transfer_transaction(vec![], &payer.pubkey());
+        
+        let signed = sign_transaction(&unsigned, &[secret(&payer)]).unwrap();
+        assert!(signed.complete);
+        
+        let transaction = decode_transaction(&signed.transaction).unwrap();
+        assert_eq!(transaction.signatures.len(), 1);
+        assert_eq!(transaction.verify_with_results(), vec![true]);
+    }
+
+    #[test]
+    fn sign_rejects_keys_that_are_not_signers() {
+        let payer = Keypair::new();
+        let unsigned = transfer_transaction(vec![Signature::default()], &payer.pubkey());
+        
+        assert!(sign_transaction(&unsigned, &[secret(&Keypair::new())]).is_err());
+        assert!(sign_transaction(&unsigned, &[]).is_err());
+    }
+
+    #[test]
+    fn merge_combines_partial_signatures() {
+        let fee_payer = Keypair::new();
+        let sender = Keypair::new();
+        let unsigned = two_signer_transaction(&fee_payer, &sender);
+        let from_fee_payer = sign_transaction(&unsigned, &[secret(&fee_payer)]).unwrap().transaction;
+        let from_sender = sign_transaction(&unsigned, &[secret(&sender)]).unwrap().transaction;
+        
+        let merged = merge_transactions(&[from_fee_payer, from_sender]).unwrap();
+        assert!(merged.complete);
+        assert!(merged.missing_signers.is_empty());
+        assert_eq!(decode_transaction(&merged.transaction).unwrap().verify_with_results(), vec![true, true]);
+    }
+
+    #[test]
+    fn merge_rejects_invalid_signatures_and_different_messages() {
+        let fee_payer = Keypair::new();
+        let sender = Keypair::new();
+        let unsigned = two_signer_transaction(&fee_payer, &sender);
+        let signed = sign_transaction(&unsigned, &[secret(&sender)]).unwrap().transaction;
+        
+        let mut forged = decode_transaction(&signed).unwrap();
+        forged.signatures[1] = sender.sign_message(b"another message");
+        let forged = general_purpose::STANDARD.encode(bincode::serialize(&forged).unwrap());
+        let error = merge_transactions(&[signed.clone(), forged]).unwrap_err();
+        assert!(error.to_string().contains("invalid signature"));
+        
+        let other = two_signer_transaction(&fee_payer, &sender);
+        let error = merge_transactions(&[signed, other]).unwrap_err();
+        assert!(error.to_string().contains("does not share the same message"));
+        
+        assert!(merge_transactions(&[]).is_err());
+    }
+
+    #[test]
+    fn explain_detects_base64_and_base58() {
+        let fee_payer = Keypair::new();
+        let sender = Keypair::new();
+        let unsigned = two_signer_transaction(&fee_payer, &sender);
+        let signed = sign_transaction(&unsigned, &[secret(&sender)]).unwrap().transaction;
+        let base58 = bs58::encode(general_purpose::STANDARD.decode(&signed).unwrap()).into_string();
+        
+        for encoded in [&signed, &base58] {
+            let explained = explain_transaction(encoded, None).unwrap();
+            assert_eq!(explained.version, "legacy");
+            let statuses: Vec<&str> = explained.signatures.iter().map(|info| info.status.as_str()).collect();
+            assert_eq!(statuses, vec!["missing", "valid"]);
+            assert_eq!(explained.instructions[0].instruction_type, "Transfer");
+        }
+        
+        assert!(explain_transaction(&base58, Some("base58")).is_ok());
+        assert!(explain_transaction(&base58, Some("base64")).is_err());
+        assert!(explain_transaction(&signed, Some("hex")).is_err());
+        
+        let error = explain_transaction("not a transaction!", None).unwrap_err();
+        assert!(error.to_string().contains("neither valid base64 nor base58"));
+    }
+
+    #[test]
+    fn nonce_build_uses_nonce_as_blockhash_and_advances_first() {
+        let fee_payer = Pubkey::new_unique();
+        let nonce_account = Pubkey::new_unique();
+        let nonce_authority = Pubkey::new_unique();
+        let nonce = Hash::new_unique();
+        let instructions = vec![transfer_spec(&fee_payer, &Pubkey::new_unique(), 1)];
+        
+        let built = build_nonce_transaction(
+            &instructions,
+            &fee_payer.to_string(),
+            &nonce_account.to_string(),
+            &nonce_authority.to_string(),
+            &nonce.to_string(),
+        ).unwrap();
+        assert_eq!(built.signers, vec![fee_payer.to_string(), nonce_authority.to_string()]);
+        
+        let explained = explain_transaction(&built.transaction, Some("base64")).unwrap();
+        assert_eq!(explained.recent_blockhash, nonce.to_string());
+        let types: Vec<&str> = explained.instructions.iter().map(|decoded| decoded.instruction_type.as_str()).collect();
+        assert_eq!(types, vec!["AdvanceNonceAccount", "Transfer"]);
+        assert_eq!(explained.instructions[0].info["nonce_account"], nonce_account.to_string());
+        assert_eq!(explained.instructions[0].info["nonce_authority"], nonce_authority.to_string());
+    }
+
+    #[test]
+    fn nonce_build_rejects_invalid_nonce() {
+        let key = Pubkey::new_unique().to_string();
+        let instructions = vec![transfer_spec(&Pubkey::new_unique(), &Pubkey::new_unique(), 1)];
+        
+        assert!(build_nonce_transaction(&instructions, &key, &key, &key, "not-a-hash").is_err());
+        assert!(build_nonce_transaction(&[], &key, &key, &key, &Hash::new_unique().to_string()).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -40,6 +40,74 @@ pub struct CreateTokenRequest {
     pub mint_authority: String,
     pub mint: String,
     pub decimals: u8,
+    // Omitted keeps the mint authority as freeze authority, `null` creates the
+    // mint without one.
+    #[serde(alias = "freezeAuthority", default, deserialize_with = "deserialize_present")]
+    pub freeze_authority: Option<Option<String>>,
+    #[serde(default)]
+    pub create_account: bool,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+    #[serde(default)]
+    pub extensions: Vec<MintExtension>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MintExtension {
+    TransferFeeConfig {
+        #[serde(default)]
+        transfer_fee_config_authority: Option<String>,
+        #[serde(default)]
+        withdraw_withheld_authority: Option<String>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    InterestBearing {
+        #[serde(default)]
+        rate_authority: Option<String>,
+        rate: i16,
+    },
+    MetadataPointer {
+        #[serde(default)]
+        authority: Option<String>,
+        #[serde(default)]
+        metadata_address: Option<String>,
+        #[serde(default)]
+        metadata: Option<TokenMetadataFields>,
+    },
+    NonTransferable,
+    PermanentDelegate {
+        delegate: String,
+    },
+    DefaultAccountState {
+        state: String,
+    },
+    MintCloseAuthority {
+        #[serde(default)]
+        close_authority: Option<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TokenMetadataFields {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    #[serde(default)]
+    pub update_authority: Option<String>,
+    #[serde(default)]
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -47,7 +115,16 @@ pub struct MintTokenRequest {
     pub mint: String,
     pub destination: String,
     pub authority: String,
+    #[serde(default)]
     pub amount: u64,
+    #[serde(default)]
+    pub ui_amount: Option<String>,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -77,19 +154,250 @@ pub struct VerifyMessageResponse {
     pub pubkey: String,
 }
 
+// Answered with a single instruction, or with `{ "instructions": [...] }`
+// when a memo or compute budget is requested.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SendSolRequest {
     pub from: String,
     pub to: String,
     pub lamports: u64,
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: Option<u64>,
+    #[serde(default)]
+    pub memo: Option<String>,
 }
 
+// Answered with a single instruction, or with `{ "instructions": [...] }`
+// when a destination ATA, memo or compute budget is requested.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SendTokenRequest {
     pub destination: String,
     pub mint: String,
     pub owner: String,
+    #[serde(default)]
+    pub amount: u64,
+    #[serde(default)]
+    pub ui_amount: Option<String>,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub create_destination_ata: bool,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: Option<u64>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+    #[serde(default)]
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateAtaRequest {
+    pub payer: String,
+    pub owner: String,
+    pub mint: String,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BurnTokenRequest {
+    pub mint: String,
+    pub owner: String,
+    #[serde(default)]
+    pub account: Option<String>,
+    pub amount: u64,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApproveTokenRequest {
+    pub mint: String,
+    pub owner: String,
+    pub delegate: String,
+    #[serde(default)]
+    pub account: Option<String>,
     pub amount: u64,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevokeTokenRequest {
+    pub mint: String,
+    pub owner: String,
+    #[serde(default)]
+    pub account: Option<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CloseTokenAccountRequest {
+    pub mint: String,
+    pub owner: String,
+    pub destination: String,
+    #[serde(default)]
+    pub account: Option<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreezeAccountRequest {
+    pub mint: String,
+    pub freeze_authority: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub account: Option<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetAuthorityRequest {
+    pub account: String,
+    pub authority_type: String,
+    pub current_authority: String,
+    #[serde(default)]
+    pub new_authority: Option<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WrapSolRequest {
+    pub owner: String,
+    pub lamports: u64,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnwrapSolRequest {
+    pub owner: String,
+    #[serde(default)]
+    pub account: Option<String>,
+    // Receives the unwrapped SOL; required when the owner is a multisig.
+    #[serde(default)]
+    pub destination: Option<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateMultisigRequest {
+    pub payer: String,
+    pub multisig: String,
+    pub signers: Vec<String>,
+    pub m: u8,
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WithdrawWithheldFeesRequest {
+    pub mint: String,
+    pub destination: String,
+    pub authority: String,
+    #[serde(default)]
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub multisig_signers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HarvestWithheldFeesRequest {
+    pub mint: String,
+    pub sources: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreatorInput {
+    pub address: String,
+    #[serde(default)]
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MetadataDataInput {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    #[serde(default)]
+    pub seller_fee_basis_points: u16,
+    #[serde(default)]
+    pub creators: Vec<CreatorInput>,
+    #[serde(default)]
+    pub collection: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateMetadataRequest {
+    pub mint: String,
+    pub mint_authority: String,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub update_authority: Option<String>,
+    #[serde(flatten)]
+    pub data: MetadataDataInput,
+    #[serde(default = "default_is_mutable")]
+    pub is_mutable: bool,
+}
+
+fn default_is_mutable() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateMetadataRequest {
+    pub mint: String,
+    pub update_authority: String,
+    #[serde(default)]
+    pub data: Option<MetadataDataInput>,
+    #[serde(default)]
+    pub new_update_authority: Option<String>,
+    #[serde(default)]
+    pub primary_sale_happened: Option<bool>,
+    #[serde(default)]
+    pub is_mutable: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MetadataInstructionResponse {
+    pub metadata: String,
+    pub instruction: InstructionResponse,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -107,22 +415,511 @@ pub struct InstructionResponse {
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct SimpleSolTransferResponse {
+pub struct InstructionListResponse {
+    pub instructions: Vec<InstructionResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AccountCreationResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub instructions: Vec<InstructionResponse>,
+    pub signers: Vec<String>,
+    pub lamports: u64,
+    pub space: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LookupTableInstructionResponse {
+    pub lookup_table: String,
+    pub recent_slot: u64,
+    pub instruction: InstructionResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SeededInstructionResponse {
+    pub address: String,
+    pub instruction: InstructionResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateAccountRequest {
+    pub payer: String,
+    pub new_account: String,
+    pub owner: String,
+    pub space: u64,
+    #[serde(default)]
+    pub lamports: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateAccountWithSeedRequest {
+    pub payer: String,
+    pub base: String,
+    pub seed: String,
+    pub owner: String,
+    pub space: u64,
+    #[serde(default)]
+    pub lamports: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AllocateRequest {
+    pub account: String,
+    pub space: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AssignRequest {
+    pub account: String,
+    pub owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransferWithSeedRequest {
+    pub from_base: String,
+    pub from_seed: String,
+    pub from_owner: String,
+    pub to: String,
+    pub lamports: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AllocateWithSeedRequest {
+    pub base: String,
+    pub seed: String,
+    pub owner: String,
+    pub space: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AssignWithSeedRequest {
+    pub base: String,
+    pub seed: String,
+    pub owner: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateNonceAccountRequest {
+    pub payer: String,
+    pub nonce_account: String,
+    #[serde(default)]
+    pub authority: Option<String>,
+    #[serde(default)]
+    pub lamports: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AdvanceNonceRequest {
+    pub nonce_account: String,
+    pub authority: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WithdrawNonceRequest {
+    pub nonce_account: String,
+    pub authority: String,
+    pub to: String,
+    pub lamports: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthorizeNonceRequest {
+    pub nonce_account: String,
+    pub authority: String,
+    pub new_authority: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NonceAccountResponse {
+    pub address: String,
+    pub authority: String,
+    pub nonce: String,
+    pub lamports_per_signature: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StakeLockup {
+    #[serde(default)]
+    pub unix_timestamp: i64,
+    #[serde(default)]
+    pub epoch: u64,
+    pub custodian: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateStakeAccountRequest {
+    pub payer: String,
+    pub stake_account: String,
+    #[serde(default)]
+    pub staker: Option<String>,
+    #[serde(default)]
+    pub withdrawer: Option<String>,
+    pub lamports: u64,
+    #[serde(default)]
+    pub lockup: Option<StakeLockup>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateStakeRequest {
+    pub stake_account: String,
+    pub staker: String,
+    pub vote_account: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeactivateStakeRequest {
+    pub stake_account: String,
+    pub staker: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WithdrawStakeRequest {
+    pub stake_account: String,
+    pub withdrawer: String,
+    pub to: String,
+    pub lamports: u64,
+    #[serde(default)]
+    pub custodian: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SplitStakeRequest {
+    pub stake_account: String,
+    pub staker: String,
+    pub split_stake_account: String,
+    pub lamports: u64,
+    #[serde(default)]
+    pub payer: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MergeStakeRequest {
+    pub destination_stake_account: String,
+    pub source_stake_account: String,
+    pub staker: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthorizeStakeRequest {
+    pub stake_account: String,
+    pub authority: String,
+    pub new_authority: String,
+    pub stake_authorize: String,
+    #[serde(default)]
+    pub custodian: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateLookupTableRequest {
+    pub authority: String,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub recent_slot: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExtendLookupTableRequest {
+    pub lookup_table: String,
+    pub authority: String,
+    #[serde(default)]
+    pub payer: Option<String>,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreezeLookupTableRequest {
+    pub lookup_table: String,
+    pub authority: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeactivateLookupTableRequest {
+    pub lookup_table: String,
+    pub authority: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CloseLookupTableRequest {
+    pub lookup_table: String,
+    pub authority: String,
+    #[serde(default)]
+    pub recipient: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MemoRequest {
+    pub memo: String,
+    #[serde(default)]
+    pub signers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ComputeBudgetRequest {
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuildTransactionRequest {
+    pub instructions: Vec<InstructionResponse>,
+    pub fee_payer: String,
+    pub recent_blockhash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuildNonceTransactionRequest {
+    pub instructions: Vec<InstructionResponse>,
+    pub fee_payer: String,
+    pub nonce_account: String,
+    pub nonce_authority: String,
+    #[serde(default)]
+    pub nonce: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuildTransactionResponse {
+    pub transaction: String,
+    pub signers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LookupTableAccount {
+    pub address: String,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuildVersionedTransactionRequest {
+    pub instructions: Vec<InstructionResponse>,
+    pub fee_payer: String,
+    pub recent_blockhash: String,
+    #[serde(default)]
+    pub lookup_tables: Vec<LookupTableAccount>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddressTableLookupResponse {
+    pub account_key: String,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuildVersionedTransactionResponse {
+    pub transaction: String,
+    pub signers: Vec<String>,
+    pub address_table_lookups: Vec<AddressTableLookupResponse>,
+    pub size: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignTransactionRequest {
+    pub transaction: String,
+    pub secrets: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MergeTransactionsRequest {
+    pub transactions: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignedTransactionResponse {
+    pub transaction: String,
+    pub signed: Vec<String>,
+    pub missing_signers: Vec<String>,
+    pub complete: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DecodeTransactionRequest {
+    pub transaction: String,
+    #[serde(default)]
+    pub encoding: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MessageHeaderResponse {
+    pub num_required_signatures: u8,
+    pub num_readonly_signed_accounts: u8,
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransactionSignatureInfo {
+    pub signer: String,
+    pub signature: String,
+    pub status: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DecodedInstruction {
+    pub program: String,
     pub program_id: String,
-    pub accounts: Vec<String>,
-    pub instruction_data: String,
+    #[serde(rename = "type")]
+    pub instruction_type: String,
+    pub info: Value,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DecodeTransactionResponse {
+    pub version: String,
+    pub header: MessageHeaderResponse,
+    pub account_keys: Vec<String>,
+    pub recent_blockhash: String,
+    pub signatures: Vec<TransactionSignatureInfo>,
+    pub instructions: Vec<DecodedInstruction>,
+    pub address_table_lookups: Vec<AddressTableLookupResponse>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct TokenAccountMeta {
+pub struct SendTransactionRequest {
+    pub transaction: String,
+    #[serde(default)]
+    pub skip_preflight: bool,
+    #[serde(default)]
+    pub max_retries: Option<usize>,
+    #[serde(default)]
+    pub wait_for_commitment: Option<String>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransactionStatusQuery {
+    #[serde(default)]
+    pub wait_for_commitment: Option<String>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignatureStatusResponse {
+    pub signature: String,
+    pub found: bool,
+    pub slot: Option<u64>,
+    pub confirmations: Option<usize>,
+    pub confirmation_status: Option<String>,
+    pub err: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commitment_reached: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SendTransactionResponse {
+    pub signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SignatureStatusResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BalanceResponse {
     pub pubkey: String,
-    #[serde(rename = "isSigner")]
-    pub is_signer: bool,
+    pub lamports: u64,
+    pub sol: f64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct TokenTransferResponse {
-    pub program_id: String,
-    pub accounts: Vec<TokenAccountMeta>,
-    pub instruction_data: String,
-}
\ No newline at end of file
+pub struct AccountInfoResponse {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub space: usize,
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TokenAccountResponse {
+    pub address: String,
+    pub mint: String,
+    pub owner: String,
+    pub amount: String,
+    pub decimals: u8,
+    pub ui_amount: String,
+    pub state: String,
+    pub token_program: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TokenAccountsResponse {
+    pub owner: String,
+    pub accounts: Vec<TokenAccountResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TokenSupplyResponse {
+    pub mint: String,
+    pub amount: String,
+    pub decimals: u8,
+    pub ui_amount: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SimulateTransactionRequest {
+    pub transaction: String,
+    #[serde(default)]
+    pub sig_verify: bool,
+    // Defaults to the opposite of `sig_verify`, since the two cannot be combined.
+    #[serde(default)]
+    pub replace_recent_blockhash: Option<bool>,
+    #[serde(default)]
+    pub accounts: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SimulationErrorResponse {
+    pub message: String,
+    pub instruction_index: Option<u8>,
+    pub instruction_error: Option<String>,
+    pub custom_code: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SimulateTransactionResponse {
+    pub err: Option<SimulationErrorResponse>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Vec<Option<AccountInfoResponse>>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BlockhashResponse {
+    pub blockhash: String,
+    pub last_valid_block_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EstimateFeesRequest {
+    #[serde(default)]
+    pub transaction: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PrioritizationFeePercentiles {
+    pub min: u64,
+    pub p25: u64,
+    pub median: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub max: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EstimateFeesResponse {
+    pub base_fee_lamports: u64,
+    pub writable_accounts: Vec<String>,
+    pub prioritization_fee_samples: usize,
+    pub prioritization_fees_micro_lamports: Option<PrioritizationFeePercentiles>,
+}